@@ -0,0 +1,308 @@
+use std::collections::{HashMap, HashSet};
+
+use swc_core::atoms::Atom;
+use swc_core::ecma::ast::*;
+use swc_core::ecma::utils::find_pat_ids;
+use swc_core::ecma::visit::{noop_visit_type, Visit, VisitWith};
+
+/// Where a module-level binding was imported from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImportedBinding {
+    /// The whole module object.
+    ///
+    /// `const _graphql = require("@nestjs/graphql")`
+    /// `const _mongoose = _interop_require_default(require("mongoose"))`
+    /// `import * as graphql from "@nestjs/graphql"`
+    Namespace { src: Atom },
+    /// A single export of the module. Default imports use the name `default`.
+    ///
+    /// `import { _ as _ts_decorate } from "@swc/helpers/_/_ts_decorate"`
+    /// `const { Field } = require("@nestjs/graphql")`
+    /// `const _ts_decorate = require("@swc/helpers/_/_ts_decorate")._`
+    Named { src: Atom, name: Atom },
+}
+
+/// Module-level bindings collected once per program, before any transform runs.
+#[derive(Debug, Default)]
+pub struct ModuleBindings {
+    imports: HashMap<Atom, ImportedBinding>,
+    /// Top-level `class` / `let` / `const` / `var` bindings, keyed by local name, with the
+    /// index of the module item that declares them. Function declarations and imports are
+    /// hoisted with their value and are not recorded.
+    declarations: HashMap<Atom, usize>,
+    /// The subset of `declarations` that hold a class: `class` declarations and
+    /// `let X = class X {}`, which SWC emits for class-decorated classes.
+    classes: HashSet<Atom>,
+    /// `import` bindings the module reads outside type positions. TypeScript elides the
+    /// import of any other binding unless decorator metadata refers to it.
+    value_imports: HashSet<Atom>,
+}
+
+impl ModuleBindings {
+    /// Collect the `import` declarations and top-level `require(...)` bindings of a program.
+    pub fn collect(program: &Program) -> Self {
+        let mut bindings = Self::default();
+
+        if let Program::Module(module) = program {
+            for (index, item) in module.body.iter().enumerate() {
+                match item {
+                    ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => {
+                        bindings.collect_import(import);
+                    }
+                    ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => {
+                        bindings.collect_decl(&export.decl, index);
+                    }
+                    // `export default class B {}`
+                    ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(export)) => {
+                        if let DefaultDecl::Class(ClassExpr {
+                            ident: Some(ident), ..
+                        }) = &export.decl
+                        {
+                            bindings.declarations.insert(ident.sym.clone(), index);
+                            bindings.classes.insert(ident.sym.clone());
+                        }
+                    }
+                    ModuleItem::Stmt(stmt) => {
+                        bindings.collect_stmt(stmt);
+                        if let Stmt::Decl(decl) = stmt {
+                            bindings.collect_decl(decl, index);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            let mut reads = ImportReads {
+                imports: &bindings.imports,
+                found: HashSet::new(),
+            };
+            module.visit_with(&mut reads);
+            bindings.value_imports = reads.found;
+        } else if let Program::Script(script) = program {
+            for (index, stmt) in script.body.iter().enumerate() {
+                bindings.collect_stmt(stmt);
+                if let Stmt::Decl(decl) = stmt {
+                    bindings.collect_decl(decl, index);
+                }
+            }
+        }
+
+        bindings
+    }
+
+    /// All import bindings, keyed by local name.
+    pub fn imports(&self) -> impl Iterator<Item = (&Atom, &ImportedBinding)> {
+        self.imports.iter()
+    }
+
+    /// Where `name` was imported from, if it is an import binding.
+    pub fn import(&self, name: &Atom) -> Option<&ImportedBinding> {
+        self.imports.get(name)
+    }
+
+    /// Whether `name` is an `import` binding that only appears in type positions, so
+    /// its import survives TypeScript only while decorator metadata refers to it.
+    pub fn is_type_only_import(&self, name: &Atom) -> bool {
+        self.imports.contains_key(name) && !self.value_imports.contains(name)
+    }
+
+    /// Whether `name` is declared at the top level, shadowing any global of that name.
+    pub fn declares(&self, name: &Atom) -> bool {
+        self.imports.contains_key(name) || self.declarations.contains_key(name)
+    }
+
+    /// Index of the top-level item that declares `name`, when the binding is not usable
+    /// before that item runs (`class`, `let`, `const`, `var`).
+    pub fn declaration_index(&self, name: &Atom) -> Option<usize> {
+        self.declarations.get(name).copied()
+    }
+
+    /// Whether `name` is a top-level class binding.
+    pub fn is_class(&self, name: &Atom) -> bool {
+        self.classes.contains(name)
+    }
+
+    fn collect_decl(&mut self, decl: &Decl, index: usize) {
+        if let Decl::Class(class) = decl {
+            self.declarations.insert(class.ident.sym.clone(), index);
+            self.classes.insert(class.ident.sym.clone());
+        } else if let Decl::Var(var) = decl {
+            for declarator in &var.decls {
+                if let (Pat::Ident(binding), Some(Expr::Class(_))) =
+                    (&declarator.name, declarator.init.as_deref())
+                {
+                    self.classes.insert(binding.id.sym.clone());
+                }
+                let ids: Vec<Ident> = find_pat_ids(&declarator.name);
+                for id in ids {
+                    self.declarations.entry(id.sym).or_insert(index);
+                }
+            }
+        }
+    }
+
+    fn collect_import(&mut self, import: &ImportDecl) {
+        if import.type_only {
+            return;
+        }
+
+        let src = import.src.value.to_atom_lossy().into_owned();
+
+        for specifier in &import.specifiers {
+            if let ImportSpecifier::Namespace(ns) = specifier {
+                self.imports.insert(
+                    ns.local.sym.clone(),
+                    ImportedBinding::Namespace { src: src.clone() },
+                );
+            } else if let ImportSpecifier::Default(default) = specifier {
+                self.imports.insert(
+                    default.local.sym.clone(),
+                    ImportedBinding::Named {
+                        src: src.clone(),
+                        name: "default".into(),
+                    },
+                );
+            } else if let ImportSpecifier::Named(named) = specifier {
+                // `import { type Foo } from "./foo"` has no runtime value.
+                if named.is_type_only {
+                    continue;
+                }
+                let name = match &named.imported {
+                    Some(ModuleExportName::Ident(ident)) => ident.sym.clone(),
+                    Some(ModuleExportName::Str(s)) => s.value.to_atom_lossy().into_owned(),
+                    _ => named.local.sym.clone(),
+                };
+                self.imports.insert(
+                    named.local.sym.clone(),
+                    ImportedBinding::Named {
+                        src: src.clone(),
+                        name,
+                    },
+                );
+            }
+        }
+    }
+
+    fn collect_stmt(&mut self, stmt: &Stmt) {
+        let Stmt::Decl(Decl::Var(var)) = stmt else {
+            return;
+        };
+
+        for decl in &var.decls {
+            let Some(init) = &decl.init else {
+                continue;
+            };
+            if let (Pat::Ident(binding), Some((src, name))) = (&decl.name, required_export(init)) {
+                self.imports
+                    .insert(binding.id.sym.clone(), ImportedBinding::Named { src, name });
+                continue;
+            }
+            let Some(src) = require_source(init) else {
+                continue;
+            };
+
+            match &decl.name {
+                Pat::Ident(binding) => {
+                    self.imports
+                        .insert(binding.id.sym.clone(), ImportedBinding::Namespace { src });
+                }
+                Pat::Object(obj) => {
+                    for prop in &obj.props {
+                        match prop {
+                            ObjectPatProp::Assign(assign) => {
+                                self.imports.insert(
+                                    assign.key.sym.clone(),
+                                    ImportedBinding::Named {
+                                        src: src.clone(),
+                                        name: assign.key.sym.clone(),
+                                    },
+                                );
+                            }
+                            ObjectPatProp::KeyValue(kv) => {
+                                if let (Some(name), Pat::Ident(local)) =
+                                    (prop_name_str(&kv.key), &*kv.value)
+                                {
+                                    self.imports.insert(
+                                        local.id.sym.clone(),
+                                        ImportedBinding::Named {
+                                            src: src.clone(),
+                                            name,
+                                        },
+                                    );
+                                }
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+struct ImportReads<'a> {
+    imports: &'a HashMap<Atom, ImportedBinding>,
+    found: HashSet<Atom>,
+}
+
+impl Visit for ImportReads<'_> {
+    noop_visit_type!();
+
+    fn visit_import_decl(&mut self, _: &ImportDecl) {
+        // The specifiers declare the bindings; they do not read them.
+    }
+
+    fn visit_ident(&mut self, ident: &Ident) {
+        if self.imports.contains_key(&ident.sym) {
+            self.found.insert(ident.sym.clone());
+        }
+    }
+}
+
+/// Extract the module specifier and export name from `require("src").name`.
+fn required_export(expr: &Expr) -> Option<(Atom, Atom)> {
+    let Expr::Member(member) = expr else {
+        return None;
+    };
+    let MemberProp::Ident(name) = &member.prop else {
+        return None;
+    };
+    Some((require_source(&member.obj)?, name.sym.clone()))
+}
+
+/// Extract the module specifier from `require("src")`, optionally wrapped in one
+/// of SWC's interop helpers (`_interop_require_default(require("src"))`).
+fn require_source(expr: &Expr) -> Option<Atom> {
+    let Expr::Call(call) = expr else {
+        return None;
+    };
+    let Callee::Expr(callee) = &call.callee else {
+        return None;
+    };
+    let Expr::Ident(ident) = &**callee else {
+        return None;
+    };
+    let arg = call.args.first()?;
+
+    if ident.sym.as_ref() == "require" {
+        return match &*arg.expr {
+            Expr::Lit(Lit::Str(s)) => Some(s.value.to_atom_lossy().into_owned()),
+            _ => None,
+        };
+    }
+
+    if ident.sym.starts_with("_interop_require") {
+        return require_source(&arg.expr);
+    }
+
+    None
+}
+
+fn prop_name_str(key: &PropName) -> Option<Atom> {
+    match key {
+        PropName::Ident(id) => Some(id.sym.clone()),
+        PropName::Str(s) => Some(s.value.to_atom_lossy().into_owned()),
+        _ => None,
+    }
+}
@@ -0,0 +1,132 @@
+use std::collections::HashSet;
+
+use swc_core::atoms::Atom;
+use swc_core::ecma::ast::*;
+use swc_core::ecma::visit::{Visit, VisitWith};
+
+use crate::detection::is_renamed_from;
+
+/// A runtime helper SWC injects into the files it compiles.
+#[derive(Debug)]
+pub struct RuntimeHelper {
+    /// Name of the inline copy. Hygiene may append a numeric suffix (`_define_property1`).
+    pub name: &'static str,
+    /// Number of declared parameters.
+    params: usize,
+    /// An identifier, property name or string literal every version of the body mentions.
+    marker: &'static str,
+    pub source: HelperSource,
+}
+
+/// Where the code of a runtime helper can be loaded from instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HelperSource {
+    /// `@swc/helpers/_/<name>`, exported as `_`.
+    Module,
+    /// A private function of another helper's module (`asyncGeneratorStep`).
+    Private { parent: &'static str },
+    /// Emitted by a module transform, with no `@swc/helpers` counterpart (`_export`).
+    Inline,
+}
+
+impl RuntimeHelper {
+    /// The module to import the helper from, if it has one.
+    pub fn module(&self) -> Option<String> {
+        (self.source == HelperSource::Module).then(|| format!("@swc/helpers/_/{}", self.name))
+    }
+}
+
+const fn module(name: &'static str, params: usize, marker: &'static str) -> RuntimeHelper {
+    RuntimeHelper {
+        name,
+        params,
+        marker,
+        source: HelperSource::Module,
+    }
+}
+
+const fn private(
+    name: &'static str,
+    params: usize,
+    marker: &'static str,
+    parent: &'static str,
+) -> RuntimeHelper {
+    RuntimeHelper {
+        name,
+        params,
+        marker,
+        source: HelperSource::Private { parent },
+    }
+}
+
+/// The helpers SWC inlines when `jsc.externalHelpers` is off, fingerprinted by parameter
+/// count and a name their bodies always use, so unrelated functions that happen to share
+/// a name are left alone.
+const CATALOGUE: &[RuntimeHelper] = &[
+    module("_ts_decorate", 4, "decorate"),
+    module("_ts_metadata", 2, "metadata"),
+    module("_ts_param", 2, "paramIndex"),
+    module("_interop_require_default", 1, "__esModule"),
+    module("_interop_require_wildcard", 2, "__esModule"),
+    private(
+        "_getRequireWildcardCache",
+        1,
+        "WeakMap",
+        "_interop_require_wildcard",
+    ),
+    module("_export_star", 2, "hasOwnProperty"),
+    module("_define_property", 3, "defineProperty"),
+    module("_async_to_generator", 1, "Promise"),
+    private("asyncGeneratorStep", 7, "Promise", "_async_to_generator"),
+    module("_object_spread", 1, "getOwnPropertySymbols"),
+    module("_object_spread_props", 2, "getOwnPropertyDescriptors"),
+    private(
+        "ownKeys",
+        2,
+        "getOwnPropertySymbols",
+        "_object_spread_props",
+    ),
+    module("_object_without_properties", 2, "getOwnPropertySymbols"),
+    module("_object_without_properties_loose", 2, "indexOf"),
+    RuntimeHelper {
+        name: "_export",
+        params: 2,
+        marker: "defineProperty",
+        source: HelperSource::Inline,
+    },
+];
+
+/// Identify a function declaration as an injected runtime helper.
+pub fn identify(fn_decl: &FnDecl) -> Option<&'static RuntimeHelper> {
+    let function = &fn_decl.function;
+    let body = function.body.as_ref()?;
+
+    let helper = CATALOGUE.iter().find(|helper| {
+        is_renamed_from(&fn_decl.ident.sym, helper.name) && function.params.len() == helper.params
+    })?;
+
+    let mut names = BodyNames::default();
+    body.visit_with(&mut names);
+    names
+        .0
+        .contains(&Atom::from(helper.marker))
+        .then_some(helper)
+}
+
+/// Identifiers, property names and string literals used in a function body.
+#[derive(Default)]
+struct BodyNames(HashSet<Atom>);
+
+impl Visit for BodyNames {
+    fn visit_ident(&mut self, ident: &Ident) {
+        self.0.insert(ident.sym.clone());
+    }
+
+    fn visit_ident_name(&mut self, ident: &IdentName) {
+        self.0.insert(ident.sym.clone());
+    }
+
+    fn visit_str(&mut self, s: &Str) {
+        self.0.insert(s.value.to_atom_lossy().into_owned());
+    }
+}
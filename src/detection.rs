@@ -1,25 +1,682 @@
+use std::collections::{HashMap, HashSet};
+
+use swc_core::atoms::Atom;
 use swc_core::ecma::ast::*;
+use swc_core::ecma::utils::find_pat_ids;
+use swc_core::ecma::visit::{Visit, VisitWith};
 
-/// Check if a CallExpr is a `_ts_decorate(...)` call.
-pub fn is_ts_decorate_call(call: &CallExpr) -> bool {
+use crate::bindings::{ImportedBinding, ModuleBindings};
+use crate::{HelperNames, PreservedThunk};
+
+/// The decorator helpers the plugin knows how to rewrite.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HelperKind {
+    /// `_ts_decorate([...], target, key, desc)` / `__decorate([...], target, key, desc)`
+    Decorate,
+    /// `_ts_metadata("design:type", ...)` / `__metadata("design:type", ...)`
+    Metadata,
+    /// `_ts_param(0, decorator)` / `__param(0, decorator)`
+    Param,
+    /// TC39 decorators (`decoratorVersion: "2022-03"` / `"2023-11"`):
+    /// `_apply_decs_2203_r(this, memberDecs, classDecs)` / `_apply_decs_2311(this, classDecs, memberDecs)`
+    ApplyDecorators,
+}
+
+/// A compiler's naming scheme for its decorator helpers.
+struct HelperDialect {
+    helpers: &'static [(&'static str, HelperKind)],
+    /// Whether hygiene may append a numeric suffix to the helper names (`_ts_decorate1`).
+    renamed_by_hygiene: bool,
+}
+
+impl HelperDialect {
+    fn exact_kind(&self, name: &str) -> Option<HelperKind> {
+        self.helpers
+            .iter()
+            .find_map(|(helper, kind)| (*helper == name).then_some(*kind))
+    }
+
+    fn local_kind(&self, sym: &str) -> Option<HelperKind> {
+        if !self.renamed_by_hygiene {
+            return self.exact_kind(sym);
+        }
+        self.helpers
+            .iter()
+            .find_map(|(name, kind)| is_renamed_from(sym, name).then_some(*kind))
+    }
+}
+
+/// Whether `sym` is `name`, possibly with a numeric suffix added by hygiene (`_ts_decorate1`).
+pub fn is_renamed_from(sym: &str, name: &str) -> bool {
+    sym.strip_prefix(name)
+        .is_some_and(|suffix| suffix.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// SWC: inline `_ts_decorate` functions or `@swc/helpers` imports.
+const SWC: HelperDialect = HelperDialect {
+    helpers: &[
+        ("_ts_decorate", HelperKind::Decorate),
+        ("_ts_metadata", HelperKind::Metadata),
+        ("_ts_param", HelperKind::Param),
+        ("_apply_decs", HelperKind::ApplyDecorators),
+        ("_apply_decs_2203", HelperKind::ApplyDecorators),
+        ("_apply_decs_2203_r", HelperKind::ApplyDecorators),
+        ("_apply_decs_2305", HelperKind::ApplyDecorators),
+        ("_apply_decs_2311", HelperKind::ApplyDecorators),
+    ],
+    renamed_by_hygiene: true,
+};
+
+/// tsc: inline `var __decorate = ...` helpers or `tslib` imports (`importHelpers`).
+const TSC: HelperDialect = HelperDialect {
+    helpers: &[
+        ("__decorate", HelperKind::Decorate),
+        ("__metadata", HelperKind::Metadata),
+        ("__param", HelperKind::Param),
+    ],
+    renamed_by_hygiene: false,
+};
+
+const DIALECTS: [&HelperDialect; 2] = [&SWC, &TSC];
+
+/// How a namespace binding exposes helpers.
+#[derive(Debug, Clone, Copy)]
+enum HelperNamespace {
+    /// A single `@swc/helpers` module, exporting its helper as `_` / `default`.
+    Module(HelperKind),
+    /// `tslib`, exporting every helper under its own name (`tslib_1.__decorate`).
+    Runtime,
+}
+
+/// Resolves call expressions to decorator helpers.
+///
+/// Besides the inline `_ts_decorate` / `_ts_metadata` / `_ts_param` functions, this
+/// recognizes:
+/// - hygiene-renamed helpers: `_ts_decorate1(...)`
+/// - `jsc.externalHelpers` CommonJS output: `(0, _ts_decorate._)(...)`
+///   where `const _ts_decorate = require("@swc/helpers/_/_ts_decorate")`
+/// - `jsc.externalHelpers` ESM output: `import { _ as _ts_decorate } from "@swc/helpers/_/_ts_decorate"`
+/// - tsc output: `__decorate(...)`, `tslib_1.__decorate(...)`, `(0, tslib_1.__decorate)(...)`
+/// - extra callee names from the `helperNames` config option
+#[derive(Debug, Default)]
+pub struct HelperResolver {
+    /// Bindings to a helper module, called through a member expression.
+    namespaces: HashMap<Atom, HelperNamespace>,
+    /// Bindings to the helper function itself.
+    functions: HashMap<Atom, HelperKind>,
+}
+
+impl HelperResolver {
+    pub fn new(bindings: &ModuleBindings, helper_names: Option<&HelperNames>) -> Self {
+        let mut resolver = Self::default();
+
+        for (local, binding) in bindings.imports() {
+            match binding {
+                ImportedBinding::Namespace { src } => {
+                    if let Some(namespace) = helper_namespace(src) {
+                        resolver.namespaces.insert(local.clone(), namespace);
+                    }
+                }
+                ImportedBinding::Named { src, name } => {
+                    let kind = match helper_namespace(src) {
+                        Some(namespace) => namespace.export_kind(name),
+                        // `import { _ts_decorate } from "@swc/helpers"`
+                        None if src == "@swc/helpers" => SWC.exact_kind(name),
+                        None => None,
+                    };
+                    if let Some(kind) = kind {
+                        resolver.functions.insert(local.clone(), kind);
+                    }
+                }
+            }
+        }
+
+        if let Some(names) = helper_names {
+            let configured = [
+                (&names.decorate, HelperKind::Decorate),
+                (&names.metadata, HelperKind::Metadata),
+                (&names.param, HelperKind::Param),
+            ];
+            for (names, kind) in configured {
+                for name in names {
+                    resolver.functions.insert(name.as_str().into(), kind);
+                }
+            }
+        }
+
+        resolver
+    }
+
+    /// Determine which helper (if any) a call expression invokes.
+    pub fn kind_of_call(&self, call: &CallExpr) -> Option<HelperKind> {
+        match callee_target(&call.callee)? {
+            Expr::Ident(ident) => self.kind_of_local(&ident.sym),
+            Expr::Member(member) => {
+                let Expr::Ident(obj) = &*member.obj else {
+                    return None;
+                };
+                let namespace = self.namespaces.get(&obj.sym)?;
+                match &member.prop {
+                    MemberProp::Ident(prop) => namespace.export_kind(&prop.sym),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Determine which helper (if any) a local binding name refers to.
+    pub fn kind_of_local(&self, sym: &Atom) -> Option<HelperKind> {
+        self.functions
+            .get(sym)
+            .copied()
+            .or_else(|| DIALECTS.iter().find_map(|d| d.local_kind(sym)))
+    }
+}
+
+impl HelperNamespace {
+    /// The helper exposed under an export name of this namespace.
+    fn export_kind(self, name: &str) -> Option<HelperKind> {
+        match self {
+            HelperNamespace::Module(kind) => {
+                (name == "_" || name == "default" || SWC.exact_kind(name) == Some(kind))
+                    .then_some(kind)
+            }
+            HelperNamespace::Runtime => TSC.exact_kind(name),
+        }
+    }
+}
+
+/// Strip the indirection SWC and tsc wrap around imported callees.
+///
+/// `(0, _graphql.Field)` -> `_graphql.Field`
+/// `(_graphql.Field)`    -> `_graphql.Field`
+pub fn callee_target(callee: &Callee) -> Option<&Expr> {
+    match callee {
+        Callee::Expr(expr) => Some(unwrap_indirection(expr)),
+        _ => None,
+    }
+}
+
+fn unwrap_indirection(expr: &Expr) -> &Expr {
+    match expr {
+        Expr::Paren(paren) => unwrap_indirection(&paren.expr),
+        Expr::Seq(seq) => match seq.exprs.last() {
+            Some(last) => unwrap_indirection(last),
+            None => expr,
+        },
+        _ => expr,
+    }
+}
+
+/// Decorator APIs that call the thunk they are given, with the argument positions that
+/// hold it. Unwrapping these hands a class to code that invokes it as a function.
+const THUNK_CALLEES: &[(&str, &[usize])] = &[
+    // class-transformer: `@Type(() => Dto)`
+    ("Type", &[0]),
+    // @nestjs/common: `forwardRef(() => UsersService)`
+    ("forwardRef", &[0]),
+    // inversify / tsyringe: `delay(() => Service)`
+    ("delay", &[0]),
+    // typeorm relations: `@ManyToOne(() => User, (user) => user.photos)`
+    ("OneToOne", &[0]),
+    ("OneToMany", &[0]),
+    ("ManyToOne", &[0]),
+    ("ManyToMany", &[0]),
+];
+
+/// Call sites whose thunk arguments must stay lazy: the built-in [`THUNK_CALLEES`] plus
+/// the `preserveThunks` config entries.
+#[derive(Debug, Default)]
+pub struct ThunkRegistry {
+    /// Argument positions by callee name. An empty list covers every argument.
+    callees: HashMap<String, Vec<usize>>,
+}
+
+impl ThunkRegistry {
+    pub fn new(extra: Option<&[PreservedThunk]>) -> Self {
+        let mut callees: HashMap<String, Vec<usize>> = THUNK_CALLEES
+            .iter()
+            .map(|(name, args)| (name.to_string(), args.to_vec()))
+            .collect();
+
+        // Entries add to the positions already listed for a callee; an empty list on
+        // either side still means every argument.
+        for entry in extra.unwrap_or_default() {
+            let args = callees
+                .entry(entry.callee.clone())
+                .or_insert_with(|| entry.args.clone());
+            if args.is_empty() || entry.args.is_empty() {
+                args.clear();
+            } else {
+                args.extend(&entry.args);
+                args.sort_unstable();
+                args.dedup();
+            }
+        }
+
+        Self { callees }
+    }
+
+    /// Whether argument `index` of `call` must keep its thunk.
+    ///
+    /// The callee is matched by name, through the `(0, _mod.Name)` indirection SWC emits
+    /// for imported functions.
+    pub fn preserves(&self, call: &CallExpr, index: usize) -> bool {
+        let Some(name) = callee_name(call) else {
+            return false;
+        };
+
+        self.callees
+            .get(name.as_str())
+            .is_some_and(|args| args.is_empty() || args.contains(&index))
+    }
+}
+
+/// The function name a call resolves to: `Name(...)`, `_mod.Name(...)` or `(0, _mod.Name)(...)`.
+fn callee_name(call: &CallExpr) -> Option<&Atom> {
+    match callee_target(&call.callee)? {
+        Expr::Ident(ident) => Some(&ident.sym),
+        Expr::Member(MemberExpr {
+            prop: MemberProp::Ident(prop),
+            ..
+        }) => Some(&prop.sym),
+        _ => None,
+    }
+}
+
+/// Parameter decorators whose pipes (`ValidationPipe`, GraphQL argument inference) read the
+/// parameter's `design:paramtypes` entry.
+const VALIDATED_PARAM_DECORATORS: [&str; 4] = ["Body", "Query", "Param", "Args"];
+
+/// Check if an expression is a `Body()` / `Query()` / `Param()` / `Args()` decorator call.
+pub fn is_validated_param_decorator(expr: &Expr) -> bool {
+    let Expr::Call(call) = expr else {
+        return false;
+    };
+    callee_name(call).is_some_and(|name| VALIDATED_PARAM_DECORATORS.contains(&name.as_ref()))
+}
+
+/// Parameter positions decorated with a validated decorator in a `_ts_decorate` array:
+/// `_ts_param(1, (0, _common.Body)())` -> `1`.
+pub fn validated_param_indices(
+    elems: &[Option<ExprOrSpread>],
+    helpers: &HelperResolver,
+) -> Vec<usize> {
+    elems
+        .iter()
+        .flatten()
+        .filter_map(|elem| {
+            let Expr::Call(call) = &*elem.expr else {
+                return None;
+            };
+            if helpers.kind_of_call(call) != Some(HelperKind::Param) {
+                return None;
+            }
+            let [index, decorator] = call.args.as_slice() else {
+                return None;
+            };
+            let Expr::Lit(Lit::Num(index)) = &*index.expr else {
+                return None;
+            };
+            is_validated_param_decorator(&decorator.expr).then_some(index.value as usize)
+        })
+        .collect()
+}
+
+/// Decorators that read `design:type` at runtime, by source module. An empty list
+/// stands for every export of the module.
+const DESIGN_TYPE_CONSUMERS: &[(&str, &[&str])] = &[
+    ("@nestjs/mongoose", &["Prop"]),
+    (
+        "typeorm",
+        &[
+            "Column",
+            "PrimaryColumn",
+            "PrimaryGeneratedColumn",
+            "CreateDateColumn",
+            "UpdateDateColumn",
+            "DeleteDateColumn",
+            "VersionColumn",
+        ],
+    ),
+    ("@nestjs/graphql", &["Field"]),
+    (
+        "@nestjs/swagger",
+        &["ApiProperty", "ApiPropertyOptional", "ApiResponseProperty"],
+    ),
+    ("class-transformer", &[]),
+];
+
+/// Check if a `_ts_decorate` array contains a decorator that reads `design:type`.
+pub fn has_design_type_consumer(elems: &[Option<ExprOrSpread>], bindings: &ModuleBindings) -> bool {
+    elems
+        .iter()
+        .flatten()
+        .any(|elem| is_design_type_consumer(&elem.expr, bindings))
+}
+
+/// Check if a decorator reads `design:type`, resolved through the module it was
+/// imported from.
+///
+/// `(0, _mongoose.Prop)()` with `const _mongoose = require("@nestjs/mongoose")` qualifies,
+/// as does `Prop()` with `import { Prop } from "@nestjs/mongoose"`;
+/// `Field(() => String)` does not, since the thunk already names the type.
+pub fn is_design_type_consumer(decorator: &Expr, bindings: &ModuleBindings) -> bool {
+    let Expr::Call(call) = decorator else {
+        return false;
+    };
+    let Some((src, name)) = imported_callee(call, bindings) else {
+        return false;
+    };
+    if src == "@nestjs/graphql" && name == "Field" && has_type_thunk(call) {
+        return false;
+    }
+    DESIGN_TYPE_CONSUMERS.iter().any(|(module, names)| {
+        src == *module && (names.is_empty() || names.contains(&name.as_ref()))
+    })
+}
+
+/// The source module and export name of an imported callee.
+fn imported_callee<'a>(
+    call: &'a CallExpr,
+    bindings: &'a ModuleBindings,
+) -> Option<(&'a Atom, &'a Atom)> {
+    match callee_target(&call.callee)? {
+        Expr::Ident(ident) => match bindings.import(&ident.sym)? {
+            ImportedBinding::Named { src, name } => Some((src, name)),
+            ImportedBinding::Namespace { .. } => None,
+        },
+        Expr::Member(MemberExpr {
+            obj,
+            prop: MemberProp::Ident(prop),
+            ..
+        }) => {
+            let Expr::Ident(namespace) = &**obj else {
+                return None;
+            };
+            match bindings.import(&namespace.sym)? {
+                ImportedBinding::Namespace { src } => Some((src, &prop.sym)),
+                ImportedBinding::Named { .. } => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn has_type_thunk(call: &CallExpr) -> bool {
     matches!(
-        &call.callee,
-        Callee::Expr(expr) if matches!(
-            &**expr,
-            Expr::Ident(ident) if ident.sym.as_ref() == "_ts_decorate"
-        )
+        call.args.first().map(|arg| &*arg.expr),
+        Some(Expr::Arrow(_) | Expr::Fn(_))
     )
 }
 
+/// Check if a CallExpr is Nest's `forwardRef(() => X)`.
+pub fn is_forward_ref_call(call: &CallExpr) -> bool {
+    callee_name(call).is_some_and(|name| name.as_ref() == "forwardRef")
+}
+
+/// Map a module specifier to the helpers it exposes.
+///
+/// `@swc/helpers/_/_ts_decorate`, `@swc/helpers/lib/_ts_decorate.js`,
+/// `@swc/helpers/cjs/_ts_decorate.cjs`, ... expose a single helper;
+/// `tslib` exposes all of them.
+fn helper_namespace(src: &str) -> Option<HelperNamespace> {
+    if src == "tslib" {
+        return Some(HelperNamespace::Runtime);
+    }
+    let path = src.strip_prefix("@swc/helpers/")?;
+    let file = path.rsplit('/').next()?;
+    SWC.exact_kind(file.split('.').next()?)
+        .map(HelperNamespace::Module)
+}
+
+/// Check if a CallExpr is a `_ts_decorate(...)` call.
+pub fn is_ts_decorate_call(call: &CallExpr, helpers: &HelperResolver) -> bool {
+    helpers.kind_of_call(call) == Some(HelperKind::Decorate)
+}
+
 /// Check if a CallExpr is a `_ts_metadata(...)` call.
-pub fn is_ts_metadata_call(call: &CallExpr) -> bool {
-    matches!(
-        &call.callee,
-        Callee::Expr(expr) if matches!(
-            &**expr,
-            Expr::Ident(ident) if ident.sym.as_ref() == "_ts_metadata"
-        )
-    )
+pub fn is_ts_metadata_call(call: &CallExpr, helpers: &HelperResolver) -> bool {
+    helpers.kind_of_call(call) == Some(HelperKind::Metadata)
+}
+
+/// Check if a CallExpr applies TC39 decorators (`_apply_decs_2203_r(...)`, `_apply_decs_2311(...)`).
+pub fn is_apply_decs_call(call: &CallExpr, helpers: &HelperResolver) -> bool {
+    helpers.kind_of_call(call) == Some(HelperKind::ApplyDecorators)
+}
+
+/// Visit every decorator expression listed in a TC39 `_apply_decs_*` call.
+///
+/// Member descriptors are arrays whose first element is the decorator (or an array
+/// of decorators): `[_dec, 0, "name"]`, `[[_dec, _dec1], 1, "items"]`.
+/// Class decorators are listed directly: `[_dec2]`.
+///
+/// The argument order differs between helper versions, so every array argument is
+/// inspected and its elements classified by shape.
+pub fn for_each_apply_decs_decorator(call: &mut CallExpr, f: &mut impl FnMut(&mut Box<Expr>)) {
+    for arg in call.args.iter_mut().skip(1) {
+        let Expr::Array(decs) = &mut *arg.expr else {
+            continue;
+        };
+        for dec in decs.elems.iter_mut().flatten() {
+            match &mut *dec.expr {
+                Expr::Array(descriptor) => {
+                    let Some(Some(first)) = descriptor.elems.first_mut() else {
+                        continue;
+                    };
+                    match &mut *first.expr {
+                        Expr::Array(member_decs) => {
+                            for member_dec in member_decs.elems.iter_mut().flatten() {
+                                f(&mut member_dec.expr);
+                            }
+                        }
+                        _ => f(&mut first.expr),
+                    }
+                }
+                _ => f(&mut dec.expr),
+            }
+        }
+    }
+}
+
+/// The getter and setter SWC generates for a decorated `accessor` field.
+///
+/// `accessor items` becomes a private field filled by the helper's initializer and a
+/// pair of methods that forward to it:
+///
+/// `#___private_items = _init_items(this);`
+/// `get items() { return this.#___private_items; }`
+/// `set items(_v) { this.#___private_items = _v; }`
+///
+/// Only classes that call an `_apply_decs_*` helper are inspected.
+pub fn generated_accessors<'a>(class: &'a Class, helpers: &HelperResolver) -> Vec<&'a ClassMethod> {
+    let mut finder = ApplyDecsFinder {
+        helpers,
+        found: false,
+    };
+    for member in &class.body {
+        if let ClassMember::StaticBlock(block) = member {
+            block.visit_with(&mut finder);
+        }
+    }
+    if !finder.found {
+        return Vec::new();
+    }
+
+    let backing: HashSet<&Atom> = class
+        .body
+        .iter()
+        .filter_map(|member| {
+            let ClassMember::PrivateProp(prop) = member else {
+                return None;
+            };
+            // 2023-11 runs the extra initializers first: `(_init_extra_items(this), _init_items(this))`
+            let value = strip_parens(prop.value.as_deref()?);
+            let is_initializer = match value {
+                Expr::Seq(seq) => seq.exprs.iter().all(|expr| is_init_call(expr)),
+                other => is_init_call(other),
+            };
+            is_initializer.then_some(&prop.key.name)
+        })
+        .collect();
+
+    class
+        .body
+        .iter()
+        .filter_map(|member| {
+            let ClassMember::Method(method) = member else {
+                return None;
+            };
+            let [stmt] = method.function.body.as_ref()?.stmts.as_slice() else {
+                return None;
+            };
+            let field = match (method.kind, stmt) {
+                (MethodKind::Getter, Stmt::Return(ReturnStmt { arg: Some(arg), .. })) => {
+                    this_private_field(arg)
+                }
+                (MethodKind::Setter, Stmt::Expr(ExprStmt { expr, .. })) => {
+                    let Expr::Assign(assign) = &**expr else {
+                        return None;
+                    };
+                    let AssignTarget::Simple(SimpleAssignTarget::Member(member)) = &assign.left
+                    else {
+                        return None;
+                    };
+                    private_field_of_this(member)
+                }
+                _ => None,
+            }?;
+            backing.contains(field).then_some(method)
+        })
+        .collect()
+}
+
+/// `_init_items(this)` or `_init_items(this, value)`.
+fn is_init_call(expr: &Expr) -> bool {
+    let Expr::Call(call) = expr else {
+        return false;
+    };
+    let Callee::Expr(callee) = &call.callee else {
+        return false;
+    };
+    matches!(&**callee, Expr::Ident(ident) if ident.sym.starts_with("_init_"))
+        && matches!(call.args.first(), Some(arg) if matches!(*arg.expr, Expr::This(_)))
+}
+
+/// `#name` in `this.#name`.
+fn this_private_field(expr: &Expr) -> Option<&Atom> {
+    let Expr::Member(member) = expr else {
+        return None;
+    };
+    private_field_of_this(member)
+}
+
+fn private_field_of_this(member: &MemberExpr) -> Option<&Atom> {
+    let MemberProp::PrivateName(name) = &member.prop else {
+        return None;
+    };
+    matches!(*member.obj, Expr::This(_)).then_some(&name.name)
+}
+
+struct ApplyDecsFinder<'a> {
+    helpers: &'a HelperResolver,
+    found: bool,
+}
+
+impl Visit for ApplyDecsFinder<'_> {
+    fn visit_call_expr(&mut self, call: &CallExpr) {
+        self.found |= is_apply_decs_call(call, self.helpers);
+        call.visit_children_with(self);
+    }
+}
+
+/// The temporaries SWC evaluates TC39 decorators into before the class body, and the
+/// classes they decorate.
+#[derive(Debug, Default)]
+pub struct ApplyDecsTemps {
+    /// `_dec`, `_dec1`, ...
+    pub temps: HashSet<Atom>,
+    /// Named classes whose body applies the temps. They are not initialized yet when
+    /// the temps are evaluated.
+    pub classes: HashSet<Atom>,
+}
+
+/// Collect the temporaries SWC evaluates TC39 decorators into before the class body.
+///
+/// `_dec = (0, _graphql.Field)(() => String), _dec1 = ...` followed by
+/// `class A { static { _apply_decs_2203_r(this, [[_dec, 0, "name"]], [_dec1]) } }`
+/// yields the temps `{_dec, _dec1}` and the class `A`.
+pub fn collect_apply_decs_temps(program: &Program, helpers: &HelperResolver) -> ApplyDecsTemps {
+    let mut collector = ApplyDecsTempCollector {
+        helpers,
+        class_name: None,
+        found: ApplyDecsTemps::default(),
+    };
+    program.visit_with(&mut collector);
+    collector.found
+}
+
+struct ApplyDecsTempCollector<'a> {
+    helpers: &'a HelperResolver,
+    /// Name of the innermost class being visited.
+    class_name: Option<Atom>,
+    found: ApplyDecsTemps,
+}
+
+impl ApplyDecsTempCollector<'_> {
+    fn visit_named_class(&mut self, name: Option<Atom>, class: &Class) {
+        let outer = std::mem::replace(&mut self.class_name, name);
+        class.visit_with(self);
+        self.class_name = outer;
+    }
+}
+
+impl Visit for ApplyDecsTempCollector<'_> {
+    fn visit_class_decl(&mut self, decl: &ClassDecl) {
+        self.visit_named_class(Some(decl.ident.sym.clone()), &decl.class);
+    }
+
+    fn visit_class_expr(&mut self, expr: &ClassExpr) {
+        self.visit_named_class(
+            expr.ident.as_ref().map(|ident| ident.sym.clone()),
+            &expr.class,
+        );
+    }
+
+    fn visit_call_expr(&mut self, call: &CallExpr) {
+        if is_apply_decs_call(call, self.helpers) {
+            if let Some(class_name) = &self.class_name {
+                self.found.classes.insert(class_name.clone());
+            }
+            for arg in call.args.iter().skip(1) {
+                let Expr::Array(decs) = &*arg.expr else {
+                    continue;
+                };
+                for dec in decs.elems.iter().flatten() {
+                    let decorator = match &*dec.expr {
+                        Expr::Array(descriptor) => match descriptor.elems.first() {
+                            Some(Some(first)) => &*first.expr,
+                            _ => continue,
+                        },
+                        other => other,
+                    };
+                    let decorators = match decorator {
+                        Expr::Array(member_decs) => {
+                            member_decs.elems.iter().flatten().map(|d| &*d.expr).collect()
+                        }
+                        single => vec![single],
+                    };
+                    for decorator in decorators {
+                        if let Expr::Ident(ident) = decorator {
+                            self.found.temps.insert(ident.sym.clone());
+                        }
+                    }
+                }
+            }
+        }
+        call.visit_children_with(self);
+    }
 }
 
 /// Check if an expression is `typeof X === "undefined"` or `typeof X.Y === "undefined"`.
@@ -75,12 +732,151 @@ fn is_typeof_or_chain(expr: &Expr) -> bool {
     }
 }
 
+/// Check if a conditional expression is tsc's typeof guard pattern:
+///
+///   `typeof (_a = typeof X !== "undefined" && X) === "function" ? _a : Object`
+///   `typeof (_b = typeof A !== "undefined" && (_c = A.B) !== void 0 && _c.C) === "function" ? _b : Object`
+///
+/// Unlike SWC's guard, the consequent is the temporary and the alternate is `Object`.
+/// Returns the temporaries assigned by the guard (hoisted by tsc into a `var _a, _b`
+/// declaration), or `None` if the conditional is not a tsc guard.
+pub fn tsc_typeof_guard_temps(cond: &CondExpr) -> Option<Vec<Atom>> {
+    let Expr::Bin(test) = &*cond.test else {
+        return None;
+    };
+    if test.op != BinaryOp::EqEqEq {
+        return None;
+    }
+    let (typeof_side, lit_side) = match (&*test.left, &*test.right) {
+        (Expr::Unary(_), _) => (&*test.left, &*test.right),
+        _ => (&*test.right, &*test.left),
+    };
+    let Expr::Unary(UnaryExpr {
+        op: UnaryOp::TypeOf,
+        arg,
+        ..
+    }) = typeof_side
+    else {
+        return None;
+    };
+    if !matches!(lit_side, Expr::Lit(Lit::Str(s)) if &*s.value == "function") {
+        return None;
+    }
+
+    let Expr::Assign(assign) = strip_parens(arg) else {
+        return None;
+    };
+    let temp = assign.left.as_ident()?;
+    if assign.op != AssignOp::Assign || !is_existence_and_chain(&assign.right) {
+        return None;
+    }
+
+    let consequent_is_temp = matches!(&*cond.cons, Expr::Ident(ident) if ident.sym == temp.sym);
+    let alternate_is_object = matches!(
+        &*cond.alt,
+        Expr::Ident(ident) if ident.sym.as_ref() == "Object"
+    );
+    if !consequent_is_temp || !alternate_is_object {
+        return None;
+    }
+
+    let mut temps = vec![temp.sym.clone()];
+    collect_chain_temps(&assign.right, &mut temps);
+    Some(temps)
+}
+
+/// The reference a tsc typeof guard (see [`tsc_typeof_guard_temps`]) yields when it is
+/// defined: `venue_service_1.VenueService`, or `_c.File` when the chain goes through temporaries.
+pub fn tsc_guarded_value(cond: &CondExpr) -> Option<&Expr> {
+    let Expr::Bin(test) = &*cond.test else {
+        return None;
+    };
+    let typeof_side = if matches!(&*test.left, Expr::Unary(_)) {
+        &*test.left
+    } else {
+        &*test.right
+    };
+    let Expr::Unary(UnaryExpr { arg, .. }) = typeof_side else {
+        return None;
+    };
+    let Expr::Assign(assign) = strip_parens(arg) else {
+        return None;
+    };
+    let mut operands = Vec::new();
+    flatten_and_chain(&assign.right, &mut operands);
+    operands.last().copied()
+}
+
+/// `check && check && ... && value`, where each check is `typeof X !== "undefined"`
+/// or `(_c = X) !== void 0`.
+fn is_existence_and_chain(expr: &Expr) -> bool {
+    let mut operands = Vec::new();
+    flatten_and_chain(expr, &mut operands);
+
+    match operands.split_last() {
+        Some((value, checks)) if !checks.is_empty() => {
+            checks.iter().all(|check| is_existence_check(check))
+                && matches!(value, Expr::Ident(_) | Expr::Member(_))
+        }
+        _ => false,
+    }
+}
+
+fn flatten_and_chain<'a>(expr: &'a Expr, operands: &mut Vec<&'a Expr>) {
+    match strip_parens(expr) {
+        Expr::Bin(bin) if bin.op == BinaryOp::LogicalAnd => {
+            flatten_and_chain(&bin.left, operands);
+            flatten_and_chain(&bin.right, operands);
+        }
+        other => operands.push(other),
+    }
+}
+
+fn is_existence_check(expr: &Expr) -> bool {
+    let Expr::Bin(bin) = strip_parens(expr) else {
+        return false;
+    };
+    if bin.op != BinaryOp::NotEqEq {
+        return false;
+    }
+    let typeof_check = matches!(
+        &*bin.left,
+        Expr::Unary(UnaryExpr { op: UnaryOp::TypeOf, .. })
+    ) && matches!(&*bin.right, Expr::Lit(Lit::Str(s)) if &*s.value == "undefined");
+    let void_check = matches!(strip_parens(&bin.left), Expr::Assign(_))
+        && matches!(&*bin.right, Expr::Unary(UnaryExpr { op: UnaryOp::Void, .. }));
+    typeof_check || void_check
+}
+
+fn collect_chain_temps(expr: &Expr, temps: &mut Vec<Atom>) {
+    match strip_parens(expr) {
+        Expr::Bin(bin) => {
+            collect_chain_temps(&bin.left, temps);
+            collect_chain_temps(&bin.right, temps);
+        }
+        Expr::Assign(assign) => {
+            if let Some(ident) = assign.left.as_ident() {
+                temps.push(ident.sym.clone());
+            }
+        }
+        _ => {}
+    }
+}
+
+fn strip_parens(expr: &Expr) -> &Expr {
+    match expr {
+        Expr::Paren(paren) => strip_parens(&paren.expr),
+        _ => expr,
+    }
+}
+
 /// Check if a `_ts_decorate` call is decorating a constructor (vs a method/property).
 ///
 /// Constructor: `_ts_decorate([...], ClassName, undefined, null)`
+/// Constructor: `__decorate([...], ClassName)` (tsc)
 /// Method:      `_ts_decorate([...], ClassName.prototype, "methodName", null)`
 ///
-/// The third argument (index 2) is `undefined` for constructors.
+/// The third argument (index 2) is `undefined` or absent for constructors.
 pub fn is_constructor_decorate(call: &CallExpr) -> bool {
     match call.args.get(2) {
         None => true,
@@ -97,14 +893,100 @@ pub fn is_constructor_decorate(call: &CallExpr) -> bool {
 /// - `() => String`           (Ident)
 /// - `() => SomeModule.Type`  (Member)
 /// - `() => [Type]`           (Array)
+/// - `type => Int`            (unused parameter)
+///
+/// The arrow must also be a plain function: async and generator arrows return a
+/// promise / iterator, and a body that reads its own parameters has no meaning outside
+/// the call (`(photo) => photo.user`).
 pub fn is_simple_arrow(arrow: &ArrowExpr) -> bool {
-    match &*arrow.body {
-        BlockStmtOrExpr::Expr(expr) => matches!(
-            &**expr,
-            Expr::Ident(_)    // () => String
-            | Expr::Member(_) // () => SomeModule.Type
-            | Expr::Array(_)  // () => [Type]
-        ),
+    if arrow.is_async || arrow.is_generator {
+        return false;
+    }
+
+    let BlockStmtOrExpr::Expr(expr) = &*arrow.body else {
+        return false;
+    };
+
+    let simple_body = matches!(
+        &**expr,
+        Expr::Ident(_)    // () => String
+        | Expr::Member(_) // () => SomeModule.Type
+        | Expr::Array(_)  // () => [Type]
+    );
+    if !simple_body {
+        return false;
+    }
+
+    if arrow.params.is_empty() {
+        return true;
+    }
+
+    let params: Vec<Ident> = find_pat_ids(&arrow.params);
+    !referenced_idents(expr)
+        .iter()
+        .any(|name| params.iter().any(|param| &param.sym == name))
+}
+
+/// Globals that exist before any module code runs.
+const SAFE_GLOBALS: &[&str] = &["String", "Number", "Boolean", "Date", "Object"];
+
+/// Scalars exported by GraphQL packages, which never take part in a user import cycle.
+const GRAPHQL_SCALARS: &[&str] = &["Int", "Float", "ID", "GraphQLISODateTime", "GraphQLJSON"];
+const GRAPHQL_SOURCES: &[&str] = &["@nestjs/graphql", "graphql"];
+
+/// Check if evaluating a thunk body eagerly is always safe: a built-in, a GraphQL scalar
+/// resolved back to its `require` / `import` source, or an array of those.
+///
+/// - `String`, `[Date]`
+/// - `_graphql.Int` (`const _graphql = require("@nestjs/graphql")`)
+/// - `ID` (`import { ID } from "@nestjs/graphql"`)
+pub fn is_eager_safe_reference(expr: &Expr, bindings: &ModuleBindings) -> bool {
+    match expr {
+        Expr::Ident(ident) => match bindings.import(&ident.sym) {
+            Some(ImportedBinding::Named { src, name }) => is_graphql_scalar(src, name),
+            Some(ImportedBinding::Namespace { .. }) => false,
+            None => {
+                SAFE_GLOBALS.contains(&ident.sym.as_ref()) && !bindings.declares(&ident.sym)
+            }
+        },
+        Expr::Member(MemberExpr {
+            obj,
+            prop: MemberProp::Ident(prop),
+            ..
+        }) => {
+            let Expr::Ident(namespace) = &**obj else {
+                return false;
+            };
+            matches!(
+                bindings.import(&namespace.sym),
+                Some(ImportedBinding::Namespace { src }) if is_graphql_scalar(src, &prop.sym)
+            )
+        }
+        Expr::Array(array) => array.elems.iter().all(|elem| {
+            matches!(elem, Some(ExprOrSpread { spread: None, expr }) if is_eager_safe_reference(expr, bindings))
+        }),
         _ => false,
     }
 }
+
+fn is_graphql_scalar(src: &Atom, name: &Atom) -> bool {
+    GRAPHQL_SOURCES.contains(&src.as_ref()) && GRAPHQL_SCALARS.contains(&name.as_ref())
+}
+
+/// Identifiers an expression reads, in source order. Property names are not included.
+pub fn referenced_idents(expr: &Expr) -> Vec<Atom> {
+    let mut collector = ReferencedIdents::default();
+    expr.visit_with(&mut collector);
+    collector.names
+}
+
+#[derive(Default)]
+struct ReferencedIdents {
+    names: Vec<Atom>,
+}
+
+impl Visit for ReferencedIdents {
+    fn visit_ident(&mut self, ident: &Ident) {
+        self.names.push(ident.sym.clone());
+    }
+}
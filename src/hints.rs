@@ -0,0 +1,129 @@
+use swc_core::common::comments::{Comment, CommentKind, Comments};
+use swc_core::common::errors::SourceMapper;
+use swc_core::common::{BytePos, Span, DUMMY_SP};
+
+use crate::CoverageProvider;
+
+const ISTANBUL_IGNORE_NEXT: &str = " istanbul ignore next ";
+const C8_IGNORE_NEXT: &str = " c8 ignore next ";
+const C8_IGNORE_LINE: &str = " c8 ignore next 0 ";
+const C8_IGNORE_START: &str = " c8 ignore start ";
+const C8_IGNORE_STOP: &str = " c8 ignore stop ";
+
+/// Attaches coverage-ignore comments in the dialect of the configured coverage provider.
+pub struct IgnoreHints<'a> {
+    comments: &'a dyn Comments,
+    provider: CoverageProvider,
+    /// Tells where a node sits on its lines, which decides the c8 hint.
+    source_map: Option<&'a dyn SourceMapper>,
+}
+
+impl<'a> IgnoreHints<'a> {
+    pub fn new(
+        comments: &'a dyn Comments,
+        provider: CoverageProvider,
+        source_map: Option<&'a dyn SourceMapper>,
+    ) -> Self {
+        Self {
+            comments,
+            provider,
+            source_map,
+        }
+    }
+
+    /// Mark the node at `span` as ignored.
+    ///
+    /// Istanbul: `/* istanbul ignore next */ () => Menu`
+    /// V8:       `/* c8 ignore next */ () => Menu`
+    ///
+    /// v8-to-istanbul reads one hint per line, so a start/stop pair on the same line
+    /// never closes. A `c8 ignore next` that follows other code only ignores its own line,
+    /// but one that begins its line also ignores the line after it, so a single-line node
+    /// at the start of its line gets `c8 ignore next 0` instead. Multi-line nodes get a
+    /// `c8 ignore start` on their first line and a `c8 ignore stop` on their last, which
+    /// hides whatever else sits on those lines, such as the decorator call that takes the
+    /// thunk. Without a source map every node is treated as single-line and mid-line.
+    /// Nodes synthesized without a source position cannot carry comments and are skipped.
+    pub fn ignore(&self, span: Span) {
+        self.ignore_until(span, None);
+    }
+
+    /// [`Self::ignore`] for a node that ends with a block, such as a class method. Codegen
+    /// drops trailing comments after class members, so the `c8 ignore stop` goes before
+    /// the block's closing brace instead.
+    pub fn ignore_ending_with(&self, span: Span, block: Span) {
+        // Codegen prints the comments leading `hi - 1` before the closing brace.
+        self.ignore_until(span, Some(block.hi - BytePos(1)));
+    }
+
+    fn ignore_until(&self, span: Span, stop_before: Option<BytePos>) {
+        if span.is_dummy() {
+            return;
+        }
+
+        match self.provider {
+            CoverageProvider::Istanbul => {
+                self.add_leading(span.lo, ISTANBUL_IGNORE_NEXT);
+            }
+            CoverageProvider::V8 if self.is_multi_line(span) => {
+                if self.add_leading(span.lo, C8_IGNORE_START) {
+                    match stop_before {
+                        Some(pos) => self
+                            .comments
+                            .add_leading(pos, block_comment(C8_IGNORE_STOP)),
+                        None => self
+                            .comments
+                            .add_trailing(span.hi, block_comment(C8_IGNORE_STOP)),
+                    }
+                }
+            }
+            CoverageProvider::V8 if self.begins_line(span) => {
+                self.add_leading(span.lo, C8_IGNORE_LINE);
+            }
+            CoverageProvider::V8 => {
+                self.add_leading(span.lo, C8_IGNORE_NEXT);
+            }
+        }
+    }
+
+    fn is_multi_line(&self, span: Span) -> bool {
+        self.source_map.is_some_and(|source_map| {
+            source_map.lookup_char_pos(span.lo).line != source_map.lookup_char_pos(span.hi).line
+        })
+    }
+
+    /// Mirrors v8-to-istanbul's own-line check, which allows anything but word
+    /// characters before the hint.
+    fn begins_line(&self, span: Span) -> bool {
+        self.source_map.is_some_and(|source_map| {
+            let loc = source_map.lookup_char_pos(span.lo);
+            loc.file.get_line(loc.line - 1).is_some_and(|line| {
+                line.chars()
+                    .take(loc.col.0)
+                    .all(|c| !c.is_ascii_alphanumeric() && c != '_')
+            })
+        })
+    }
+
+    /// Returns false when the same hint is already attached at `pos`.
+    fn add_leading(&self, pos: BytePos, text: &str) -> bool {
+        let already_hinted = self
+            .comments
+            .get_leading(pos)
+            .is_some_and(|comments| comments.iter().any(|comment| &*comment.text == text));
+        if already_hinted {
+            return false;
+        }
+
+        self.comments.add_leading(pos, block_comment(text));
+        true
+    }
+}
+
+fn block_comment(text: &str) -> Comment {
+    Comment {
+        kind: CommentKind::Block,
+        span: DUMMY_SP,
+        text: text.into(),
+    }
+}
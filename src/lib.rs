@@ -1,3 +1,4 @@
+use swc_core::common::sync::Lrc;
 use swc_core::ecma::{ast::Program, visit::visit_mut_pass};
 use swc_core::plugin::{
     metadata::TransformPluginMetadataContextKind, plugin_transform,
@@ -5,7 +6,10 @@ use swc_core::plugin::{
 };
 use serde::Deserialize;
 
+mod bindings;
+mod catalogue;
 mod detection;
+mod hints;
 mod transforms;
 pub mod visitor;
 
@@ -16,10 +20,13 @@ use visitor::DecoratorCoverageVisitor;
 pub struct Config {
     /// Unwrap simple arrow functions in decorator type params (default: true)
     /// e.g., `type: () => String` -> `type: String`
-    pub unwrap_type_arrows: Option<bool>,
+    /// Also accepts `{ enabled, keys, presets }` to cover other property names
+    pub unwrap_type_arrows: Option<UnwrapTypeArrows>,
     /// Strip _ts_metadata calls from _ts_decorate arrays (default: false)
     /// Removes design:type, design:paramtypes, design:returntype
-    pub strip_metadata: Option<bool>,
+    /// Also accepts the list of keys to remove, e.g. `["design:returntype"]`
+    /// Constructor `design:paramtypes` are always kept for DI
+    pub strip_metadata: Option<StripMetadata>,
     /// Unwrap arrow function arguments to decorator calls (default: true)
     /// e.g., `ResolveField(() => String)` -> `ResolveField(String)`
     pub unwrap_decorator_arrows: Option<bool>,
@@ -29,16 +36,302 @@ pub struct Config {
     /// Simplify typeof guard conditionals inside _ts_metadata("design:type", ...) args (default: false)
     /// Only enable if your design:type metadata contains member-expression types (e.g. mongoose.Types.ObjectId)
     pub simplify_design_type_typeofs: Option<bool>,
+    /// Further _ts_metadata keys whose typeof guards are simplified, e.g. `design:returntype`
+    /// `{ "presets": ["returntype"], "keys": ["custom:type"] }`
+    pub simplify_typeofs: Option<SimplifyTypeofs>,
+    /// What simplified typeof guards are replaced with (default: "object")
+    /// "alternate" keeps the guarded reference when the module provably defines it
+    pub simplify_to: Option<SimplifyTo>,
+    /// Keep the paramtypes of `Body()` / `Query()` / `Param()` / `Args()` parameters (default: true)
+    /// Their guards are only replaced with the real type, never with `Object`
+    pub keep_validated_param_types: Option<bool>,
+    /// Keep `design:type` for members with a decorator that reads it (default: true)
+    /// e.g., `@Prop()` from `@nestjs/mongoose`, `@Column()` from `typeorm`, `@ApiProperty()`
+    /// Applies to `stripMetadata` and `simplifyDesignTypeTypeofs`
+    pub keep_consumed_design_types: Option<bool>,
+    /// Extra callee names to treat as decorator helpers (default: none)
+    /// e.g., `{ "decorate": ["__myDecorate"] }` for a custom helper bundle
+    pub helper_names: Option<HelperNames>,
+    /// How phantom branches are removed from coverage (default: "rewrite")
+    /// "ignoreHints" leaves the code unchanged and adds `/* istanbul ignore next */` comments
+    pub mode: Option<Mode>,
+    /// Coverage provider Jest runs with (default: "istanbul")
+    /// Selects the ignore-comment dialect and, for "v8", hints block-bodied thunks left by rewrites
+    pub coverage_provider: Option<CoverageProvider>,
+    /// Extra decorator APIs whose thunk arguments are never unwrapped (default: none)
+    /// Added to the built-in list (`Type`, `forwardRef`, `delay`, typeorm relations)
+    /// e.g., `[{ "callee": "Lazy", "args": [0] }]`
+    pub preserve_thunks: Option<Vec<PreservedThunk>>,
+    /// How unwrapped thunks are replaced (default: "eager")
+    /// "lazy" turns `() => _user.User` into `Reflect.get.bind(null, _user, "User")`
+    pub unwrap_strategy: Option<UnwrapStrategy>,
+    /// Only unwrap thunks over built-ins and GraphQL scalars, or arrays of them (default: false)
+    /// e.g., `() => String` and `() => [_graphql.Int]` are unwrapped, `() => _user.User` is kept
+    pub conservative_unwrap: Option<bool>,
+    /// How many object/array levels `unwrapTypeArrows` searches inside decorator options (default: 4)
+    /// e.g., `Prop({ type: [{ type: () => Sub }] })` needs 3
+    pub type_arrow_depth: Option<usize>,
+    /// Mark inline helper definitions (`_ts_decorate`, `_interop_require_default`, ...) as ignored (default: false)
+    /// Uses the `coverageProvider` comment syntax
+    pub ignore_helpers: Option<bool>,
+    /// Replace inline SWC runtime helpers with `@swc/helpers` imports (default: false)
+    pub externalize_helpers: Option<bool>,
+    /// Remove the phantom branch of enum and namespace IIFEs (default: false)
+    /// e.g., `(function(Role) { ... })(Role || (Role = {}))` -> `(...)(Role = {})`
+    pub normalize_enum_iifes: Option<bool>,
+    /// Replace or ignore the getter functions of CommonJS re-exports (default: false)
+    /// e.g., `get: function() { return _x.X; }` -> `get: Reflect.get.bind(null, _x, "X")`
+    pub normalize_reexport_getters: Option<bool>,
+}
+
+/// `unwrapTypeArrows`: a plain switch, or the property keys to unwrap.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum UnwrapTypeArrows {
+    Enabled(bool),
+    Options(TypeArrowOptions),
+}
+
+/// Object form of `unwrapTypeArrows`, e.g.
+/// `{ "presets": ["graphql"], "keys": ["itemType"] }`.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TypeArrowOptions {
+    /// Defaults to true, so listing keys is enough to enable the transform.
+    pub enabled: Option<bool>,
+    /// Extra property names holding type thunks, on top of `type`.
+    #[serde(default)]
+    pub keys: Vec<String>,
+    /// Built-in key sets to add.
+    #[serde(default)]
+    pub presets: Vec<TypeKeyPreset>,
+}
+
+/// Framework-specific property names that hold type thunks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TypeKeyPreset {
+    /// `@ObjectType({ implements: () => [Node] })`
+    Graphql,
+    /// `@Prop({ ref: () => Owner })`
+    Mongoose,
+    /// `@ManyToOne({ entity: () => User })`
+    MikroOrm,
+}
+
+impl TypeKeyPreset {
+    pub fn keys(self) -> &'static [&'static str] {
+        match self {
+            TypeKeyPreset::Graphql => &["implements"],
+            TypeKeyPreset::Mongoose => &["ref"],
+            TypeKeyPreset::MikroOrm => &["entity"],
+        }
+    }
+}
+
+impl From<bool> for UnwrapTypeArrows {
+    fn from(enabled: bool) -> Self {
+        UnwrapTypeArrows::Enabled(enabled)
+    }
+}
+
+impl UnwrapTypeArrows {
+    pub fn is_enabled(&self) -> bool {
+        match self {
+            UnwrapTypeArrows::Enabled(enabled) => *enabled,
+            UnwrapTypeArrows::Options(options) => options.enabled.unwrap_or(true),
+        }
+    }
+
+    /// The property names to unwrap: always `type`, plus any preset and custom keys.
+    pub fn keys(&self) -> Vec<String> {
+        let mut keys = vec!["type".to_string()];
+        if let UnwrapTypeArrows::Options(options) = self {
+            for preset in &options.presets {
+                keys.extend(preset.keys().iter().map(|key| key.to_string()));
+            }
+            keys.extend(options.keys.iter().cloned());
+        }
+        keys.sort();
+        keys.dedup();
+        keys
+    }
+}
+
+/// `stripMetadata`: a plain switch, or the metadata keys to strip.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum StripMetadata {
+    Enabled(bool),
+    Keys(Vec<String>),
+}
+
+impl From<bool> for StripMetadata {
+    fn from(enabled: bool) -> Self {
+        StripMetadata::Enabled(enabled)
+    }
+}
+
+impl StripMetadata {
+    pub fn is_enabled(&self) -> bool {
+        match self {
+            StripMetadata::Enabled(enabled) => *enabled,
+            StripMetadata::Keys(keys) => !keys.is_empty(),
+        }
+    }
+
+    /// Whether `_ts_metadata` calls with this key are stripped. The list form only
+    /// matches string keys it names.
+    pub fn strips(&self, key: Option<&str>) -> bool {
+        match self {
+            StripMetadata::Enabled(enabled) => *enabled,
+            StripMetadata::Keys(keys) => key.is_some_and(|key| keys.iter().any(|k| k == key)),
+        }
+    }
+}
+
+/// `simplifyTypeofs`, e.g. `{ "presets": ["returntype"], "keys": ["custom:type"] }`.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimplifyTypeofs {
+    /// Metadata keys to simplify.
+    #[serde(default)]
+    pub keys: Vec<String>,
+    /// Built-in keys to add.
+    #[serde(default)]
+    pub presets: Vec<TypeofKeyPreset>,
+}
+
+/// The `design:*` keys TypeScript emits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TypeofKeyPreset {
+    /// `design:paramtypes`, as with `simplifyMetadataTypeofs`
+    Paramtypes,
+    /// `design:type`, as with `simplifyDesignTypeTypeofs`
+    DesignType,
+    /// `design:returntype`
+    Returntype,
+}
+
+impl TypeofKeyPreset {
+    pub fn key(self) -> &'static str {
+        match self {
+            TypeofKeyPreset::Paramtypes => "design:paramtypes",
+            TypeofKeyPreset::DesignType => "design:type",
+            TypeofKeyPreset::Returntype => "design:returntype",
+        }
+    }
+}
+
+impl SimplifyTypeofs {
+    /// The preset and custom keys.
+    pub fn keys(&self) -> Vec<String> {
+        let mut keys: Vec<String> = self
+            .presets
+            .iter()
+            .map(|preset| preset.key().to_string())
+            .chain(self.keys.iter().cloned())
+            .collect();
+        keys.sort();
+        keys.dedup();
+        keys
+    }
+}
+
+/// What a simplified typeof guard is replaced with.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SimplifyTo {
+    /// `Object`, whatever the guard protects.
+    #[default]
+    Object,
+    /// The guarded reference (`_config.ConfigService`) when it is an import or a class
+    /// declared earlier in the module, `Object` otherwise.
+    Alternate,
+}
+
+/// What an unwrapped `() => X` thunk is replaced with.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum UnwrapStrategy {
+    /// The body itself: `() => _user.User` -> `_user.User`.
+    #[default]
+    Eager,
+    /// A bound lookup for member bodies, so callers still resolve the export when they
+    /// call it: `Reflect.get.bind(null, _user, "User")`. Other bodies are unwrapped eagerly.
+    Lazy,
+}
+
+/// A callee whose thunk arguments must stay lazy, matched by name.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PreservedThunk {
+    pub callee: String,
+    /// Argument positions holding thunks. Empty covers every argument.
+    #[serde(default)]
+    pub args: Vec<usize>,
+}
+
+/// How the plugin hides decorator-generated branches from coverage.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Mode {
+    /// Rewrite the AST: unwrap thunks, simplify guards, strip metadata.
+    #[default]
+    Rewrite,
+    /// Keep the AST as-is and attach coverage-ignore comments to thunks and guards.
+    IgnoreHints,
+}
+
+/// Jest's `coverageProvider`, which decides how ignore hints are spelled.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CoverageProvider {
+    /// `/* istanbul ignore next */`, read by Istanbul's instrumenter.
+    #[default]
+    Istanbul,
+    /// `/* c8 ignore next */`, or `/* c8 ignore start */` / `/* c8 ignore stop */` around
+    /// multi-line nodes, read by v8-to-istanbul.
+    V8,
+}
+
+/// Extra callee names for each decorator helper, on top of the built-in
+/// `_ts_decorate` / `_ts_metadata` / `_ts_param` and their `@swc/helpers` imports.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HelperNames {
+    #[serde(default)]
+    pub decorate: Vec<String>,
+    #[serde(default)]
+    pub metadata: Vec<String>,
+    #[serde(default)]
+    pub param: Vec<String>,
 }
 
 impl Default for Config {
     fn default() -> Self {
         Self {
-            unwrap_type_arrows: Some(true),
-            strip_metadata: Some(false),
+            unwrap_type_arrows: Some(true.into()),
+            strip_metadata: Some(false.into()),
             unwrap_decorator_arrows: Some(true),
             simplify_metadata_typeofs: Some(true),
             simplify_design_type_typeofs: Some(false),
+            simplify_typeofs: None,
+            simplify_to: Some(SimplifyTo::Object),
+            keep_validated_param_types: Some(true),
+            keep_consumed_design_types: Some(true),
+            helper_names: None,
+            mode: Some(Mode::Rewrite),
+            coverage_provider: Some(CoverageProvider::Istanbul),
+            preserve_thunks: None,
+            unwrap_strategy: Some(UnwrapStrategy::Eager),
+            conservative_unwrap: Some(false),
+            type_arrow_depth: Some(4),
+            ignore_helpers: Some(false),
+            externalize_helpers: Some(false),
+            normalize_enum_iifes: Some(false),
+            normalize_reexport_getters: Some(false),
         }
     }
 }
@@ -47,8 +340,14 @@ impl Config {
     /// Merge an override on top of self. Override's Some values win; None inherits from self.
     pub fn merge_override(&self, override_config: &Config) -> Config {
         Config {
-            unwrap_type_arrows: override_config.unwrap_type_arrows.or(self.unwrap_type_arrows),
-            strip_metadata: override_config.strip_metadata.or(self.strip_metadata),
+            unwrap_type_arrows: override_config
+                .unwrap_type_arrows
+                .clone()
+                .or_else(|| self.unwrap_type_arrows.clone()),
+            strip_metadata: override_config
+                .strip_metadata
+                .clone()
+                .or_else(|| self.strip_metadata.clone()),
             unwrap_decorator_arrows: override_config
                 .unwrap_decorator_arrows
                 .or(self.unwrap_decorator_arrows),
@@ -58,12 +357,48 @@ impl Config {
             simplify_design_type_typeofs: override_config
                 .simplify_design_type_typeofs
                 .or(self.simplify_design_type_typeofs),
+            simplify_typeofs: override_config
+                .simplify_typeofs
+                .clone()
+                .or_else(|| self.simplify_typeofs.clone()),
+            simplify_to: override_config.simplify_to.or(self.simplify_to),
+            keep_validated_param_types: override_config
+                .keep_validated_param_types
+                .or(self.keep_validated_param_types),
+            keep_consumed_design_types: override_config
+                .keep_consumed_design_types
+                .or(self.keep_consumed_design_types),
+            helper_names: override_config
+                .helper_names
+                .clone()
+                .or_else(|| self.helper_names.clone()),
+            mode: override_config.mode.or(self.mode),
+            coverage_provider: override_config.coverage_provider.or(self.coverage_provider),
+            preserve_thunks: override_config
+                .preserve_thunks
+                .clone()
+                .or_else(|| self.preserve_thunks.clone()),
+            unwrap_strategy: override_config.unwrap_strategy.or(self.unwrap_strategy),
+            conservative_unwrap: override_config
+                .conservative_unwrap
+                .or(self.conservative_unwrap),
+            type_arrow_depth: override_config.type_arrow_depth.or(self.type_arrow_depth),
+            ignore_helpers: override_config.ignore_helpers.or(self.ignore_helpers),
+            externalize_helpers: override_config
+                .externalize_helpers
+                .or(self.externalize_helpers),
+            normalize_enum_iifes: override_config
+                .normalize_enum_iifes
+                .or(self.normalize_enum_iifes),
+            normalize_reexport_getters: override_config
+                .normalize_reexport_getters
+                .or(self.normalize_reexport_getters),
         }
     }
 }
 
 /// Top-level config shape. Backward compatible via #[serde(flatten)].
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Default, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PluginConfig {
     /// Base config options (flattened for backward compat with existing JSON shape)
@@ -75,15 +410,6 @@ pub struct PluginConfig {
     pub overrides: Vec<OverrideRule>,
 }
 
-impl Default for PluginConfig {
-    fn default() -> Self {
-        Self {
-            base: Config::default(),
-            overrides: Vec::new(),
-        }
-    }
-}
-
 impl PluginConfig {
     /// Resolve the final Config for a given filename by applying all matching overrides.
     pub fn resolve(&self, filename: Option<&str>) -> Config {
@@ -144,5 +470,8 @@ pub fn process_transform(
     let filename = metadata.get_context(&TransformPluginMetadataContextKind::Filename);
     let config = plugin_config.resolve(filename.as_deref());
 
-    program.apply(visit_mut_pass(DecoratorCoverageVisitor::new(config)))
+    program.apply(visit_mut_pass(
+        DecoratorCoverageVisitor::with_comments(config, metadata.comments)
+            .with_source_map(Lrc::new(metadata.source_map)),
+    ))
 }
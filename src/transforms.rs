@@ -1,49 +1,322 @@
-use swc_core::common::DUMMY_SP;
+use std::collections::HashSet;
+
+use swc_core::atoms::Atom;
+use swc_core::common::errors::HANDLER;
+use swc_core::common::util::take::Take;
+use swc_core::common::{Span, Spanned, DUMMY_SP};
 use swc_core::ecma::ast::*;
+use swc_core::ecma::utils::{find_pat_ids, ExprFactory};
+use swc_core::ecma::visit::{Visit, VisitMut, VisitMutWith, VisitWith};
 
+use crate::bindings::ModuleBindings;
+use crate::catalogue::{self, HelperSource, RuntimeHelper};
 use crate::detection::*;
+use crate::hints::IgnoreHints;
 
 /// Remove `_ts_metadata(...)` calls from a decorators array.
 ///
 /// Before: `[decorator1, _ts_metadata("design:type", Function), decorator2]`
 /// After:  `[decorator1, decorator2]`
-pub fn strip_metadata_calls(elems: &mut Vec<Option<ExprOrSpread>>) {
+///
+/// Only calls accepted by `strips` are removed. tsc guard temporaries referenced by
+/// the removed calls are added to `temps`.
+pub fn strip_metadata_calls(
+    elems: &mut Vec<Option<ExprOrSpread>>,
+    helpers: &HelperResolver,
+    temps: &mut HashSet<Atom>,
+    strips: impl Fn(&CallExpr) -> bool,
+) {
     elems.retain(|elem| {
         if let Some(ExprOrSpread { expr, .. }) = elem {
             if let Expr::Call(call) = &**expr {
-                return !is_ts_metadata_call(call);
+                if is_ts_metadata_call(call, helpers) && strips(call) {
+                    for arg in &call.args {
+                        collect_guard_temps(&arg.expr, temps);
+                    }
+                    return false;
+                }
             }
         }
         true
     });
 }
 
+fn collect_guard_temps(expr: &Expr, temps: &mut HashSet<Atom>) {
+    match expr {
+        Expr::Cond(cond) => {
+            if let Some(guard_temps) = tsc_typeof_guard_temps(cond) {
+                temps.extend(guard_temps);
+            }
+        }
+        Expr::Array(array) => {
+            for elem in array.elems.iter().flatten() {
+                collect_guard_temps(&elem.expr, temps);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Decides what happens to each arrow thunk found in a decorator expression.
+pub struct ThunkPolicy<'a> {
+    /// Replace simple arrows with their body. Off in `mode: "ignoreHints"`.
+    pub unwrap: bool,
+    /// Coverage-ignore sink. `None` when the host provides no comment storage.
+    pub hints: Option<&'a IgnoreHints<'a>>,
+    /// Mark every arrow that is kept, not only the ones that must stay lazy
+    /// (`mode: "ignoreHints"` and the V8 provider).
+    pub hint_kept: bool,
+    pub bindings: &'a ModuleBindings,
+    /// Top-level item whose evaluation runs the decorators. Thunks over bindings
+    /// declared after it are kept.
+    pub item_index: Option<usize>,
+    /// Set when the decorators run before the classes they decorate are initialized, as
+    /// with the `_dec` temps of `_apply_decs_*` output, and holds those classes. Thunks
+    /// over them are kept unless they are declared in an earlier item, and bindings
+    /// declared in the same item count as declared after it.
+    pub before_class: Option<&'a HashSet<Atom>>,
+    /// Call sites whose thunk arguments must stay lazy.
+    pub registry: &'a ThunkRegistry,
+    /// `unwrapStrategy: "lazy"`: member thunks become bound lookups instead of values.
+    pub lazy: bool,
+    /// `conservativeUnwrap`: only unwrap eagerly when the body is a known-safe reference.
+    pub conservative: bool,
+}
+
+impl ThunkPolicy<'_> {
+    /// Unwrap (or mark) the arrow function held in `slot`, if it is one.
+    ///
+    /// `keep_lazy` marks registry sites, which are never unwrapped eagerly. Hints also
+    /// reach those and block-bodied arrows that cannot be unwrapped safely.
+    ///
+    /// `bindable` lets `unwrapStrategy: "lazy"` replace the arrow with a bound lookup.
+    /// Option properties are not bindable: `@nestjs/swagger` only treats `type` as a thunk
+    /// when the function is named `type`, and a bound function is named `bound get`.
+    /// Those arrows are kept instead.
+    fn apply(&self, slot: &mut Box<Expr>, keep_lazy: bool, bindable: bool) {
+        let Expr::Arrow(arrow) = &**slot else {
+            return;
+        };
+
+        if self.unwrap && self.lazy {
+            if let Some(thunk) = self.bound_thunk(arrow) {
+                if bindable {
+                    **slot = thunk;
+                } else if self.hint_kept {
+                    self.hint(arrow.span);
+                }
+                return;
+            }
+        }
+
+        if self.unwrap && !keep_lazy && is_simple_arrow(arrow) {
+            if let BlockStmtOrExpr::Expr(body) = &*arrow.body {
+                if self.conservative && !is_eager_safe_reference(body, self.bindings) {
+                    if self.hint_kept {
+                        self.hint(arrow.span);
+                    }
+                    return;
+                }
+
+                match self.late_binding(body) {
+                    Some(name) => warn_kept_thunk(arrow.span, &name),
+                    None => {
+                        *slot = body.clone();
+                        return;
+                    }
+                }
+            }
+        }
+
+        if self.hint_kept {
+            self.hint(arrow.span);
+        }
+    }
+
+    /// `forwardRef(() => X)`: the reference has to stay lazy, but the arrow is still a
+    /// function nothing calls during tests.
+    ///
+    /// Before: `(0, _common.forwardRef)(() => _users.UsersService)`
+    /// After:  `(0, _common.forwardRef)(Reflect.get.bind(null, _users, "UsersService"))`
+    ///
+    /// Bodies that cannot be rebuilt without a function (`() => UsersService`) get an
+    /// ignore hint instead.
+    fn apply_forward_ref(&self, slot: &mut Box<Expr>) {
+        let Expr::Arrow(arrow) = &**slot else {
+            return;
+        };
+
+        if self.unwrap {
+            if let Some(thunk) = self.bound_thunk(arrow) {
+                **slot = thunk;
+                return;
+            }
+        }
+
+        self.hint(arrow.span);
+    }
+
+    /// [`bound_member_thunk`], unless binding the namespace would itself read it too early.
+    fn bound_thunk(&self, arrow: &ArrowExpr) -> Option<Expr> {
+        let thunk = bound_member_thunk(arrow)?;
+        if referenced_idents(&thunk)
+            .iter()
+            .any(|name| self.is_late(name))
+        {
+            return None;
+        }
+        Some(thunk)
+    }
+
+    fn hint(&self, span: Span) {
+        if let Some(hints) = self.hints {
+            hints.ignore(span);
+        }
+    }
+
+    /// First identifier in `body` bound by a declaration that has not run yet when the
+    /// decorators are evaluated. Unwrapping such a thunk reads the binding in its TDZ.
+    ///
+    /// `_ts_decorate([(0, _graphql.Field)(() => Child)], ...); class Child {}`
+    fn late_binding(&self, body: &Expr) -> Option<Atom> {
+        referenced_idents(body)
+            .into_iter()
+            .find(|name| self.is_late(name))
+    }
+
+    fn is_late(&self, name: &Atom) -> bool {
+        let declared = self.bindings.declaration_index(name);
+        let Some(item_index) = self.item_index else {
+            return false;
+        };
+        match (declared, self.before_class) {
+            (Some(index), before_class) => {
+                index > item_index || (before_class.is_some() && index == item_index)
+            }
+            // A class nested in a function or block.
+            (None, Some(classes)) => classes.contains(name),
+            (None, None) => false,
+        }
+    }
+}
+
+/// A callable that reads `ns.Name` when invoked, without a source function for coverage
+/// to count: `() => _users.UsersService` -> `Reflect.get.bind(null, _users, "UsersService")`.
+///
+/// Only `namespace.Name` bodies qualify, so the bound object is a module-level binding.
+pub fn bound_member_thunk(arrow: &ArrowExpr) -> Option<Expr> {
+    if !is_simple_arrow(arrow) {
+        return None;
+    }
+    let BlockStmtOrExpr::Expr(body) = &*arrow.body else {
+        return None;
+    };
+    bound_member_lookup(body, arrow.span)
+}
+
+/// `ns.Name` -> `Reflect.get.bind(null, ns, "Name")`
+fn bound_member_lookup(expr: &Expr, span: Span) -> Option<Expr> {
+    let Expr::Member(MemberExpr {
+        obj,
+        prop: MemberProp::Ident(prop),
+        ..
+    }) = expr
+    else {
+        return None;
+    };
+    let Expr::Ident(namespace) = &**obj else {
+        return None;
+    };
+
+    let reflect_get_bind = Ident::new_no_ctxt("Reflect".into(), DUMMY_SP)
+        .make_member(IdentName::new("get".into(), DUMMY_SP))
+        .make_member(IdentName::new("bind".into(), DUMMY_SP));
+
+    Some(Expr::Call(CallExpr {
+        span,
+        callee: reflect_get_bind.as_callee(),
+        args: vec![
+            Lit::Null(Null { span: DUMMY_SP }).as_arg(),
+            namespace.clone().as_arg(),
+            Lit::Str(Str {
+                span: prop.span,
+                value: prop.sym.clone().into(),
+                raw: None,
+            })
+            .as_arg(),
+        ],
+        ..Default::default()
+    }))
+}
+
+/// Walk a whole decorator expression, including option objects and arrays such as
+/// `Module({ imports: [forwardRef(() => UsersModule)] })`, for `forwardRef` thunks.
+pub fn rewrite_forward_refs(expr: &mut Box<Expr>, policy: &ThunkPolicy) {
+    expr.visit_mut_with(&mut ForwardRefRewriter { policy });
+}
+
+struct ForwardRefRewriter<'a, 'b> {
+    policy: &'a ThunkPolicy<'b>,
+}
+
+impl VisitMut for ForwardRefRewriter<'_, '_> {
+    fn visit_mut_call_expr(&mut self, call: &mut CallExpr) {
+        call.visit_mut_children_with(self);
+
+        if is_forward_ref_call(call) {
+            if let Some(arg) = call.args.first_mut() {
+                self.policy.apply_forward_ref(&mut arg.expr);
+            }
+        }
+    }
+
+    // Arrow bodies only run later; their forwardRef calls are not decorator arguments.
+    fn visit_mut_arrow_expr(&mut self, _: &mut ArrowExpr) {}
+
+    fn visit_mut_function(&mut self, _: &mut Function) {}
+}
+
+fn warn_kept_thunk(span: Span, name: &Atom) {
+    if !HANDLER.is_set() {
+        return;
+    }
+
+    HANDLER.with(|handler| {
+        handler.span_warn(
+            span,
+            &format!(
+                "decorator thunk kept: `{name}` is declared after this decorator runs, so \
+                 unwrapping it would read `{name}` before initialization"
+            ),
+        );
+    });
+}
+
 /// Unwrap arrow function arguments passed directly to decorator calls.
 ///
 /// Before: `(0, _graphql.ResolveField)(() => String)`
 /// After:  `(0, _graphql.ResolveField)(String)`
 ///
 /// Also handles nested calls like `_ts_param(0, (0, _graphql.Args)(() => String))`.
-pub fn unwrap_decorator_arrow_args(elems: &mut Vec<Option<ExprOrSpread>>) {
+pub fn unwrap_decorator_arrow_args(elems: &mut [Option<ExprOrSpread>], policy: &ThunkPolicy) {
     for elem in elems.iter_mut().flatten() {
-        unwrap_arrows_in_call_args(&mut elem.expr);
+        rewrite_forward_refs(&mut elem.expr, policy);
+        unwrap_arrows_in_call_args(&mut elem.expr, policy);
     }
 }
 
-fn unwrap_arrows_in_call_args(expr: &mut Box<Expr>) {
+pub fn unwrap_arrows_in_call_args(expr: &mut Box<Expr>, policy: &ThunkPolicy) {
     if let Expr::Call(call) = &mut **expr {
-        for arg in &mut call.args {
+        let keep_lazy: Vec<bool> = (0..call.args.len())
+            .map(|index| policy.registry.preserves(call, index))
+            .collect();
+
+        for (arg, keep_lazy) in call.args.iter_mut().zip(keep_lazy) {
             // Recurse into nested calls first (e.g., _ts_param wrapping another call)
-            unwrap_arrows_in_call_args(&mut arg.expr);
+            unwrap_arrows_in_call_args(&mut arg.expr, policy);
 
             // Then unwrap arrow functions at this level
-            if let Expr::Arrow(arrow) = &*arg.expr {
-                if is_simple_arrow(arrow) {
-                    if let BlockStmtOrExpr::Expr(body) = &*arrow.body {
-                        arg.expr = body.clone();
-                    }
-                }
-            }
+            policy.apply(&mut arg.expr, keep_lazy, true);
         }
     }
 }
@@ -53,44 +326,75 @@ fn unwrap_arrows_in_call_args(expr: &mut Box<Expr>) {
 ///
 /// Before: `_ts_param(0, (0, _graphql.Args)('id', { type: () => String }))`
 /// After:  `_ts_param(0, (0, _graphql.Args)('id', { type: String }))`
-pub fn unwrap_type_arrow_props(elems: &mut Vec<Option<ExprOrSpread>>) {
+///
+/// Option trees are searched through object values and array elements up to `depth`
+/// levels, so nested thunks are reached as well:
+/// `(0, _mongoose.Prop)({ type: [{ type: () => Sub }] })`,
+/// `(0, _swagger.ApiProperty)({ items: { type: () => Dto } })`.
+/// Depth 1 only covers the properties of objects passed directly to a call.
+pub fn unwrap_type_arrow_props(
+    elems: &mut [Option<ExprOrSpread>],
+    policy: &ThunkPolicy,
+    search: &TypePropSearch,
+) {
     for elem in elems.iter_mut().flatten() {
-        unwrap_type_props_in_expr(&mut elem.expr);
+        unwrap_type_props_in_expr(&mut elem.expr, policy, search);
     }
 }
 
-fn unwrap_type_props_in_expr(expr: &mut Box<Expr>) {
+/// Which option properties hold type thunks, and how many levels to search.
+pub struct TypePropSearch<'a> {
+    /// `type` by default; `unwrapTypeArrows: { keys, presets }` adds more.
+    pub keys: &'a [String],
+    pub depth: usize,
+}
+
+pub fn unwrap_type_props_in_expr(
+    expr: &mut Box<Expr>,
+    policy: &ThunkPolicy,
+    search: &TypePropSearch,
+) {
+    unwrap_type_props_at_depth(expr, policy, search.keys, search.depth);
+}
+
+fn unwrap_type_props_at_depth(
+    expr: &mut Box<Expr>,
+    policy: &ThunkPolicy,
+    keys: &[String],
+    depth: usize,
+) {
     match &mut **expr {
+        // Nested calls are decorator arguments, not option levels.
         Expr::Call(call) => {
             for arg in &mut call.args {
-                unwrap_type_props_in_expr(&mut arg.expr);
+                unwrap_type_props_at_depth(&mut arg.expr, policy, keys, depth);
             }
         }
-        Expr::Object(obj) => {
+        Expr::Object(obj) if depth > 0 => {
             for prop in &mut obj.props {
                 if let PropOrSpread::Prop(prop) = prop {
                     if let Prop::KeyValue(kv) = &mut **prop {
-                        if is_type_key(&kv.key) {
-                            if let Expr::Arrow(arrow) = &*kv.value {
-                                if is_simple_arrow(arrow) {
-                                    if let BlockStmtOrExpr::Expr(body) = &*arrow.body {
-                                        kv.value = body.clone();
-                                    }
-                                }
-                            }
+                        if is_type_key(&kv.key, keys) {
+                            policy.apply(&mut kv.value, false, false);
                         }
+                        unwrap_type_props_at_depth(&mut kv.value, policy, keys, depth - 1);
                     }
                 }
             }
         }
+        Expr::Array(array) if depth > 0 => {
+            for elem in array.elems.iter_mut().flatten() {
+                unwrap_type_props_at_depth(&mut elem.expr, policy, keys, depth - 1);
+            }
+        }
         _ => {}
     }
 }
 
-fn is_type_key(key: &PropName) -> bool {
+fn is_type_key(key: &PropName, keys: &[String]) -> bool {
     match key {
-        PropName::Ident(id) => id.sym.as_ref() == "type",
-        PropName::Str(s) => &*s.value == "type",
+        PropName::Ident(id) => keys.iter().any(|key| key == id.sym.as_ref()),
+        PropName::Str(s) => keys.iter().any(|key| &*s.value == key.as_str()),
         _ => false,
     }
 }
@@ -102,8 +406,13 @@ fn is_type_key(key: &PropName) -> bool {
 ///
 /// Before: `_ts_metadata("design:paramtypes", [typeof X === "undefined" ? Object : X])`
 /// After:  `_ts_metadata("design:paramtypes", [Object])`
-pub fn simplify_metadata_typeof_guards(elems: &mut Vec<Option<ExprOrSpread>>) {
-    simplify_typeof_guards_for_metadata(elems, is_paramtypes_metadata);
+pub fn simplify_metadata_typeof_guards(
+    elems: &mut [Option<ExprOrSpread>],
+    helpers: &HelperResolver,
+    temps: &mut HashSet<Atom>,
+    policy: &GuardPolicy,
+) {
+    simplify_typeof_guards_for_metadata(elems, helpers, temps, policy, is_paramtypes_metadata);
 }
 
 /// Simplify typeof guard conditionals inside `_ts_metadata("design:type", ...)` arguments.
@@ -113,17 +422,121 @@ pub fn simplify_metadata_typeof_guards(elems: &mut Vec<Option<ExprOrSpread>>) {
 ///
 /// Before: `_ts_metadata("design:type", typeof mongoose === "undefined" || ... ? Object : mongoose.Types.ObjectId)`
 /// After:  `_ts_metadata("design:type", Object)`
-pub fn simplify_design_type_typeof_guards(elems: &mut Vec<Option<ExprOrSpread>>) {
-    simplify_typeof_guards_for_metadata(elems, is_design_type_metadata);
+pub fn simplify_design_type_typeof_guards(
+    elems: &mut [Option<ExprOrSpread>],
+    helpers: &HelperResolver,
+    temps: &mut HashSet<Atom>,
+    policy: &GuardPolicy,
+) {
+    simplify_typeof_guards_for_metadata(elems, helpers, temps, policy, is_design_type_metadata);
+}
+
+/// Simplify typeof guard conditionals inside `_ts_metadata` calls with any of `keys`.
+///
+/// Covers `design:returntype` and custom reflect-metadata keys (`simplifyTypeofs`).
+///
+/// Before: `_ts_metadata("design:returntype", typeof Promise === "undefined" ? Object : Promise)`
+/// After:  `_ts_metadata("design:returntype", Object)`
+pub fn simplify_keyed_typeof_guards(
+    elems: &mut [Option<ExprOrSpread>],
+    helpers: &HelperResolver,
+    temps: &mut HashSet<Atom>,
+    policy: &GuardPolicy,
+    keys: &[&str],
+) {
+    simplify_typeof_guards_for_metadata(elems, helpers, temps, policy, |call| {
+        metadata_key(call).is_some_and(|key| keys.contains(&key))
+    });
+}
+
+/// What a simplified typeof guard is replaced with.
+pub struct GuardPolicy<'a> {
+    /// The module bindings that prove a guarded reference defined.
+    pub bindings: &'a ModuleBindings,
+    pub item_index: Option<usize>,
+    /// `simplifyTo: "alternate"`: replace guards over defined references with the reference.
+    pub alternate: bool,
+    /// Replace guards over references that cannot be proven defined with `Object`.
+    /// Off for constructors, whose paramtypes drive DI.
+    pub object_fallback: bool,
+    /// Positions in `design:paramtypes` a validation pipe reads (`_ts_param(0, Body())`).
+    /// Guards there are only ever replaced with the guarded reference.
+    pub validated_params: Vec<usize>,
+}
+
+impl GuardPolicy<'_> {
+    /// The expression a guard over `value` simplifies to, if any.
+    fn replacement(&self, value: Option<&Expr>, validated: bool) -> Option<Expr> {
+        if self.alternate || validated {
+            if let Some(value) = value.filter(|value| self.is_defined(value)) {
+                return Some(value.clone());
+            }
+        }
+        (self.object_fallback && !validated)
+            .then(|| Expr::Ident(Ident::new_no_ctxt("Object".into(), DUMMY_SP)))
+    }
+
+    /// An import binding or a member of one, or a class declared before the decorated item.
+    fn is_defined(&self, value: &Expr) -> bool {
+        let mut root = value;
+        while let Expr::Member(MemberExpr {
+            obj,
+            prop: MemberProp::Ident(_),
+            ..
+        }) = root
+        {
+            root = obj;
+        }
+        let Expr::Ident(root_ident) = root else {
+            return false;
+        };
+        self.is_defined_root(&root_ident.sym, matches!(value, Expr::Ident(_)))
+    }
+
+    /// [`Self::is_defined`] for the type reference SWC would turn into a guard.
+    fn is_defined_type(&self, type_name: &TsEntityName) -> bool {
+        type_root(type_name).is_some_and(|root| {
+            self.is_defined_root(root, matches!(type_name, TsEntityName::Ident(_)))
+        })
+    }
+
+    /// `bare` is false for members, which are only defined through an import.
+    fn is_defined_root(&self, root: &Atom, bare: bool) -> bool {
+        if self.bindings.import(root).is_some() {
+            return true;
+        }
+
+        let is_class = bare && self.bindings.is_class(root);
+        let declared_before = matches!(
+            (self.bindings.declaration_index(root), self.item_index),
+            (Some(declared), Some(item)) if declared < item
+        );
+        is_class && declared_before
+    }
+}
+
+/// `Foo` in `Foo`, `Foo.Bar` and `Foo.Bar.Baz`.
+fn type_root(type_name: &TsEntityName) -> Option<&Atom> {
+    let mut root = type_name;
+    while let TsEntityName::TsQualifiedName(qualified) = root {
+        root = &qualified.left;
+    }
+    match root {
+        TsEntityName::Ident(root_ident) => Some(&root_ident.sym),
+        _ => None,
+    }
 }
 
 fn simplify_typeof_guards_for_metadata(
-    elems: &mut Vec<Option<ExprOrSpread>>,
-    key_matches: fn(&CallExpr) -> bool,
+    elems: &mut [Option<ExprOrSpread>],
+    helpers: &HelperResolver,
+    temps: &mut HashSet<Atom>,
+    policy: &GuardPolicy,
+    key_matches: impl Fn(&CallExpr) -> bool,
 ) {
     for elem in elems.iter_mut().flatten() {
         if let Expr::Call(call) = &mut *elem.expr {
-            if !is_ts_metadata_call(call) {
+            if !is_ts_metadata_call(call, helpers) {
                 continue;
             }
 
@@ -132,7 +545,7 @@ fn simplify_typeof_guards_for_metadata(
             }
 
             for arg in call.args.iter_mut().skip(1) {
-                simplify_typeofs_in_expr(&mut arg.expr);
+                simplify_typeofs_in_expr(&mut arg.expr, temps, policy, false);
             }
         }
     }
@@ -147,28 +560,828 @@ fn is_design_type_metadata(call: &CallExpr) -> bool {
 }
 
 fn metadata_key_equals(call: &CallExpr, key: &str) -> bool {
-    matches!(
-        call.args.first(),
-        Some(ExprOrSpread { expr, .. })
-            if matches!(&**expr, Expr::Lit(Lit::Str(s)) if &*s.value == key)
-    )
+    metadata_key(call) == Some(key)
 }
 
-fn simplify_typeofs_in_expr(expr: &mut Box<Expr>) {
+/// The string key of a `_ts_metadata(key, value)` call.
+pub fn metadata_key(call: &CallExpr) -> Option<&str> {
+    match call.args.first().map(|arg| &*arg.expr) {
+        Some(Expr::Lit(Lit::Str(s))) => s.value.as_str(),
+        _ => None,
+    }
+}
+
+/// `validated` marks a guard at a position in [`GuardPolicy::validated_params`].
+fn simplify_typeofs_in_expr(
+    expr: &mut Box<Expr>,
+    temps: &mut HashSet<Atom>,
+    policy: &GuardPolicy,
+    validated: bool,
+) {
     match &**expr {
         Expr::Cond(cond) if is_typeof_guard_conditional(cond) => {
-            *expr = Box::new(Expr::Ident(Ident::new_no_ctxt(
-                "Object".into(),
-                DUMMY_SP,
-            )));
+            if let Some(replacement) = policy.replacement(Some(&cond.alt), validated) {
+                **expr = replacement;
+            }
+        }
+        Expr::Cond(cond) => {
+            if let Some(guard_temps) = tsc_typeof_guard_temps(cond) {
+                if let Some(replacement) = policy.replacement(tsc_guarded_value(cond), validated) {
+                    temps.extend(guard_temps);
+                    **expr = replacement;
+                }
+            }
         }
         Expr::Array(_) => {
+            // `design:paramtypes`: one entry per parameter.
             if let Expr::Array(array) = &mut **expr {
-                for elem in array.elems.iter_mut().flatten() {
-                    simplify_typeofs_in_expr(&mut elem.expr);
+                for (index, elem) in array.elems.iter_mut().enumerate() {
+                    if let Some(elem) = elem {
+                        let validated = policy.validated_params.contains(&index);
+                        simplify_typeofs_in_expr(&mut elem.expr, temps, policy, validated);
+                    }
                 }
             }
         }
         _ => {}
     }
 }
+
+/// Replace inline SWC runtime helper definitions with bindings to the `@swc/helpers` modules.
+///
+/// Before: `function _ts_decorate(decorators, target, key, desc) { ... }`
+/// After:  `const _ts_decorate = require("@swc/helpers/_/_ts_decorate")._;` (CommonJS)
+///         `import { _ as _ts_decorate } from "@swc/helpers/_/_ts_decorate";` (ES modules)
+///
+/// The bindings keep the local names, so call sites are untouched. Unlike the function
+/// declarations they replace, `const` bindings are not hoisted, so they are inserted after
+/// the directive prologue, ahead of SWC's `_interop_require_default(require(...))` calls.
+/// Private functions of an externalized helper (`asyncGeneratorStep`) are dropped with it.
+///
+/// A program with neither `import`/`export` nor a reference to `require`, `module` or
+/// `exports` could be either, so its helpers are left inline.
+pub fn externalize_inline_helpers(program: &mut Program) {
+    let is_esm = matches!(
+        program,
+        Program::Module(module)
+            if module.body.iter().any(|item| matches!(item, ModuleItem::ModuleDecl(_)))
+    );
+    if !is_esm && !references_commonjs(program) {
+        return;
+    }
+
+    if let Program::Module(module) = program {
+        let Some((index, helpers)) = take_inline_helpers(&mut module.body, ModuleItem::as_stmt)
+        else {
+            return;
+        };
+        let bindings = helpers.into_iter().map(|(local, src)| {
+            if is_esm {
+                helper_import(local, src)
+            } else {
+                ModuleItem::Stmt(helper_require(local, src))
+            }
+        });
+        module.body.splice(index..index, bindings);
+    } else if let Program::Script(script) = program {
+        let Some((index, helpers)) =
+            take_inline_helpers(&mut script.body, |stmt: &Stmt| Some(stmt))
+        else {
+            return;
+        };
+        let bindings = helpers
+            .into_iter()
+            .map(|(local, src)| helper_require(local, src));
+        script.body.splice(index..index, bindings);
+    }
+}
+
+fn references_commonjs(program: &Program) -> bool {
+    let mut finder = CommonJsFinder { found: false };
+    program.visit_with(&mut finder);
+    finder.found
+}
+
+struct CommonJsFinder {
+    found: bool,
+}
+
+impl Visit for CommonJsFinder {
+    fn visit_ident(&mut self, ident: &Ident) {
+        self.found |= matches!(ident.sym.as_ref(), "require" | "module" | "exports");
+    }
+}
+
+/// Remove the externalizable helper declarations from `body`, returning the index after
+/// the directive prologue and the local name and module of each.
+fn take_inline_helpers<T>(
+    body: &mut Vec<T>,
+    as_stmt: impl Fn(&T) -> Option<&Stmt>,
+) -> Option<(usize, Vec<(Ident, String)>)> {
+    let found: Vec<(usize, &FnDecl, &RuntimeHelper)> = body
+        .iter()
+        .enumerate()
+        .filter_map(|(index, item)| {
+            let Stmt::Decl(Decl::Fn(fn_decl)) = as_stmt(item)? else {
+                return None;
+            };
+            Some((index, fn_decl, catalogue::identify(fn_decl)?))
+        })
+        .collect();
+
+    let externalized: HashSet<&str> = found
+        .iter()
+        .filter(|(_, _, helper)| helper.module().is_some())
+        .map(|(_, _, helper)| helper.name)
+        .collect();
+    let mut removed = HashSet::new();
+    let mut helpers = Vec::new();
+    for (index, fn_decl, helper) in found {
+        if let Some(src) = helper.module() {
+            helpers.push((fn_decl.ident.clone(), src));
+        } else if !matches!(
+            helper.source,
+            HelperSource::Private { parent } if externalized.contains(parent)
+        ) {
+            continue;
+        }
+        removed.insert(index);
+    }
+
+    if removed.is_empty() {
+        return None;
+    }
+    let mut index = 0;
+    body.retain(|_| {
+        index += 1;
+        !removed.contains(&(index - 1))
+    });
+
+    let prologue = body
+        .iter()
+        .take_while(|item| {
+            matches!(
+                as_stmt(item),
+                Some(Stmt::Expr(ExprStmt { expr, .. })) if matches!(&**expr, Expr::Lit(Lit::Str(_)))
+            )
+        })
+        .count();
+    Some((prologue, helpers))
+}
+
+/// `import { _ as local } from "src";`
+fn helper_import(local: Ident, src: String) -> ModuleItem {
+    ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+        span: DUMMY_SP,
+        specifiers: vec![ImportSpecifier::Named(ImportNamedSpecifier {
+            span: DUMMY_SP,
+            local,
+            imported: Some(ModuleExportName::Ident(Ident::new_no_ctxt(
+                "_".into(),
+                DUMMY_SP,
+            ))),
+            is_type_only: false,
+        })],
+        src: Box::new(src.into()),
+        type_only: false,
+        with: None,
+        phase: Default::default(),
+    }))
+}
+
+/// `const local = require("src")._;`
+fn helper_require(local: Ident, src: String) -> Stmt {
+    let init = Ident::new_no_ctxt("require".into(), DUMMY_SP)
+        .as_call(DUMMY_SP, vec![Str::from(src).as_arg()])
+        .make_member(IdentName::new("_".into(), DUMMY_SP));
+
+    Stmt::Decl(Decl::Var(Box::new(VarDecl {
+        kind: VarDeclKind::Const,
+        decls: vec![VarDeclarator {
+            span: DUMMY_SP,
+            name: Pat::Ident(local.into()),
+            init: Some(Box::new(init.into())),
+            definite: false,
+        }],
+        ..Default::default()
+    })))
+}
+
+/// Mark the inline helper definitions of a program as ignored by coverage.
+///
+/// Before: `function _ts_decorate(decorators, target, key, desc) { ... }`
+/// After:  `/* istanbul ignore next */ function _ts_decorate(decorators, target, key, desc) { ... }`
+///
+/// Covers the decorator helpers, including tsc's
+/// `var __decorate = (this && this.__decorate) || function (...) { ... }` statements,
+/// and the other runtime helpers SWC injects (`_interop_require_default`, `_define_property`).
+/// Only top-level definitions are considered; imported helpers are not instrumented anyway.
+pub fn hint_inline_helpers(program: &Program, helpers: &HelperResolver, hints: &IgnoreHints) {
+    if let Program::Module(module) = program {
+        for stmt in module.body.iter().filter_map(ModuleItem::as_stmt) {
+            hint_inline_helper(stmt, helpers, hints);
+        }
+    } else if let Program::Script(script) = program {
+        for stmt in &script.body {
+            hint_inline_helper(stmt, helpers, hints);
+        }
+    }
+}
+
+fn hint_inline_helper(stmt: &Stmt, helpers: &HelperResolver, hints: &IgnoreHints) {
+    if let Stmt::Decl(Decl::Fn(fn_decl)) = stmt {
+        if helpers.kind_of_local(&fn_decl.ident.sym).is_some()
+            || catalogue::identify(fn_decl).is_some()
+        {
+            hints.ignore(fn_decl.function.span);
+        }
+    } else if let Stmt::Decl(Decl::Var(var)) = stmt {
+        let all_helpers = var.decls.iter().all(|decl| {
+            decl.init.is_some()
+                && matches!(
+                    &decl.name,
+                    Pat::Ident(binding) if helpers.kind_of_local(&binding.id.sym).is_some()
+                )
+        });
+        if all_helpers {
+            hints.ignore(var.span);
+        }
+    }
+}
+
+/// Mark the getter and setter SWC generates for a decorated `accessor` field as ignored.
+/// They only forward to the backing private field, so tests rarely call both.
+///
+/// Before: `get items() { return this.#___private_items; }`
+/// After:  `/* istanbul ignore next */ get items() { return this.#___private_items; }`
+pub fn hint_generated_accessors(class: &Class, helpers: &HelperResolver, hints: &IgnoreHints) {
+    for method in generated_accessors(class, helpers) {
+        if let Some(body) = &method.function.body {
+            hints.ignore_ending_with(method.span, body.span);
+        }
+    }
+}
+
+/// Mark typeof guard conditionals inside `_ts_metadata(...)` arguments as ignored,
+/// leaving the runtime value untouched (`mode: "ignoreHints"`).
+///
+/// Before: `_ts_metadata("design:paramtypes", [typeof X === "undefined" ? Object : X])`
+/// After:  `_ts_metadata("design:paramtypes", [/* istanbul ignore next */ typeof X === "undefined" ? Object : X])`
+///
+/// Every metadata key is covered, including constructor `design:paramtypes`, since
+/// nothing reads the comments at runtime.
+pub fn hint_metadata_typeof_guards(
+    elems: &[Option<ExprOrSpread>],
+    helpers: &HelperResolver,
+    hints: &IgnoreHints,
+) {
+    for elem in elems.iter().flatten() {
+        if let Expr::Call(call) = &*elem.expr {
+            if !is_ts_metadata_call(call, helpers) {
+                continue;
+            }
+
+            for arg in call.args.iter().skip(1) {
+                hint_typeofs_in_expr(&arg.expr, hints);
+            }
+        }
+    }
+}
+
+fn hint_typeofs_in_expr(expr: &Expr, hints: &IgnoreHints) {
+    match expr {
+        Expr::Cond(cond)
+            if is_typeof_guard_conditional(cond) || tsc_typeof_guard_temps(cond).is_some() =>
+        {
+            hints.ignore(cond.span);
+        }
+        Expr::Array(array) => {
+            for elem in array.elems.iter().flatten() {
+                hint_typeofs_in_expr(&elem.expr, hints);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Pre-transform equivalent of the typeof guard simplifications, for programs where the
+/// plugin runs before SWC's decorator pass (`jsc.experimental.runPluginFirst`).
+///
+/// SWC serializes every type reference in `design:*` metadata into a typeof guard, and the
+/// `object` keyword into a plain `Object`. Rewriting the annotation therefore makes SWC
+/// emit the already-simplified metadata.
+///
+/// Before: `upload(file: Express.Multer.File)`
+/// After:  `upload(file: object)`
+///
+/// With `simplifyTo: "alternate"`, references the module provably defines are kept.
+/// References to an import the module otherwise only uses as a type are kept too: SWC
+/// keeps that import alive for the metadata alone, and rewriting the last reference
+/// would drop its `require`, along with the module's side effects.
+pub fn simplify_param_type_refs<'a>(
+    params: impl IntoIterator<Item = &'a mut Param>,
+    policy: &GuardPolicy,
+) {
+    for param in params {
+        let type_ann = match &mut param.pat {
+            Pat::Ident(binding) => &mut binding.type_ann,
+            Pat::Assign(assign) => match &mut *assign.left {
+                Pat::Ident(binding) => &mut binding.type_ann,
+                _ => continue,
+            },
+            _ => continue,
+        };
+        simplify_type_ref(type_ann, policy);
+    }
+}
+
+/// Rewrite a single type reference annotation to `object`. See [simplify_param_type_refs].
+pub fn simplify_type_ref(type_ann: &mut Option<Box<TsTypeAnn>>, policy: &GuardPolicy) {
+    if let Some(ann) = type_ann {
+        if let TsType::TsTypeRef(type_ref) = &*ann.type_ann {
+            if policy.alternate && policy.is_defined_type(&type_ref.type_name) {
+                return;
+            }
+            if type_root(&type_ref.type_name)
+                .is_some_and(|root| policy.bindings.is_type_only_import(root))
+            {
+                return;
+            }
+            *ann.type_ann = TsType::TsKeywordType(TsKeywordType {
+                span: type_ref.span,
+                kind: TsKeywordTypeKind::TsObjectKeyword,
+            });
+        }
+    }
+}
+
+/// Remove declarators for tsc guard temporaries that are no longer referenced
+/// after their guards were simplified.
+///
+/// Before: `var _a, _b;` (with every `_a` guard simplified away)
+/// After:  `var _b;`
+///
+/// Declarations left without declarators are dropped entirely.
+pub fn remove_unused_temp_vars(program: &mut Program, temps: &HashSet<Atom>) {
+    if temps.is_empty() {
+        return;
+    }
+
+    let mut counter = TempRefCounter {
+        temps,
+        referenced: HashSet::new(),
+    };
+    program.visit_with(&mut counter);
+
+    let unused: HashSet<Atom> = temps
+        .iter()
+        .filter(|temp| !counter.referenced.contains(*temp))
+        .cloned()
+        .collect();
+    if unused.is_empty() {
+        return;
+    }
+
+    program.visit_mut_with(&mut TempVarPruner { unused });
+}
+
+struct TempRefCounter<'a> {
+    temps: &'a HashSet<Atom>,
+    referenced: HashSet<Atom>,
+}
+
+impl Visit for TempRefCounter<'_> {
+    fn visit_var_declarator(&mut self, decl: &VarDeclarator) {
+        // The binding itself is not a reference; only its initializer can be.
+        decl.init.visit_with(self);
+    }
+
+    fn visit_ident(&mut self, ident: &Ident) {
+        if self.temps.contains(&ident.sym) {
+            self.referenced.insert(ident.sym.clone());
+        }
+    }
+}
+
+struct TempVarPruner {
+    unused: HashSet<Atom>,
+}
+
+impl TempVarPruner {
+    fn prune(&self, var: &mut VarDecl) -> bool {
+        let before = var.decls.len();
+        var.decls.retain(|decl| {
+            let is_unused_temp = decl.init.is_none()
+                && matches!(&decl.name, Pat::Ident(binding) if self.unused.contains(&binding.id.sym));
+            !is_unused_temp
+        });
+        before != var.decls.len() && var.decls.is_empty()
+    }
+}
+
+impl VisitMut for TempVarPruner {
+    fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+        items.retain_mut(|item| match item {
+            ModuleItem::Stmt(Stmt::Decl(Decl::Var(var))) => !self.prune(var),
+            _ => true,
+        });
+        items.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
+        stmts.retain_mut(|stmt| match stmt {
+            Stmt::Decl(Decl::Var(var)) => !self.prune(var),
+            _ => true,
+        });
+        stmts.visit_mut_children_with(self);
+    }
+}
+
+/// Remove the phantom `||` branch from the IIFEs TypeScript enums and namespaces compile to.
+///
+/// Before: `var Role; (function(Role) { ... })(Role || (Role = {}));`
+/// After:  `var Role; (function(Role) { ... })(Role = {});`
+///
+/// Only an IIFE directly after the uninitialized declaration of its target is rewritten,
+/// when that `var` is also the first declaration of the name; there the binding is always
+/// still `undefined`. Redeclarations (`var Role = {...}; var Role;`), later IIFEs of a
+/// merged enum, nested namespaces (`B = A.B || (A.B = {})`) and every guard in
+/// `mode: "ignoreHints"` get an ignore hint instead, when comments are available.
+pub fn normalize_enum_iifes(
+    program: &mut Program,
+    bindings: &ModuleBindings,
+    rewrite: bool,
+    hints: Option<&IgnoreHints>,
+) {
+    program.visit_mut_with(&mut EnumIifeNormalizer {
+        bindings,
+        rewrite,
+        hints,
+    });
+}
+
+struct EnumIifeNormalizer<'a> {
+    /// Top-level declarations, which also see hoisted `var`s of other statements.
+    bindings: &'a ModuleBindings,
+    rewrite: bool,
+    hints: Option<&'a IgnoreHints<'a>>,
+}
+
+impl EnumIifeNormalizer<'_> {
+    /// `top_level` checks each `var` against the first top-level declaration of its names.
+    fn normalize<T>(
+        &self,
+        items: &mut [T],
+        top_level: bool,
+        var_decl: fn(&T) -> Option<&VarDecl>,
+        as_stmt: fn(&mut T) -> Option<&mut Stmt>,
+    ) {
+        let mut declared: Vec<Id> = Vec::new();
+        let mut seen: HashSet<Id> = HashSet::new();
+
+        for (index, item) in items.iter_mut().enumerate() {
+            let fresh = std::mem::take(&mut declared);
+            if let Some(var) = var_decl(item) {
+                let first_declaration = |id: &Id| {
+                    !seen.contains(id)
+                        && (!top_level || self.bindings.declaration_index(&id.0) == Some(index))
+                };
+                declared = var
+                    .decls
+                    .iter()
+                    .filter(|decl| decl.init.is_none())
+                    .filter_map(|decl| decl.name.as_ident().map(|binding| binding.to_id()))
+                    .filter(first_declaration)
+                    .collect();
+                for decl in &var.decls {
+                    let ids: Vec<Id> = find_pat_ids(&decl.name);
+                    seen.extend(ids);
+                }
+                continue;
+            }
+
+            let Some(stmt) = as_stmt(item) else {
+                continue;
+            };
+            if let Stmt::Decl(
+                Decl::Fn(FnDecl { ident, .. }) | Decl::Class(ClassDecl { ident, .. }),
+            ) = stmt
+            {
+                seen.insert(ident.to_id());
+                continue;
+            }
+            let Stmt::Expr(ExprStmt { expr, .. }) = stmt else {
+                continue;
+            };
+            let Some(arg) = iife_arg(expr) else {
+                continue;
+            };
+
+            if let Some(target) = guarded_target(arg) {
+                if self.rewrite && fresh.contains(&target) {
+                    strip_enum_guard(arg);
+                } else {
+                    self.hint(arg.span());
+                }
+            } else if let Expr::Assign(AssignExpr { right, .. }) = &**arg {
+                // `B = A.B || (A.B = {})`
+                if is_member_guard(right) {
+                    self.hint(right.span());
+                }
+            }
+        }
+    }
+
+    fn hint(&self, span: Span) {
+        if let Some(hints) = self.hints {
+            hints.ignore(span);
+        }
+    }
+}
+
+impl VisitMut for EnumIifeNormalizer<'_> {
+    fn visit_mut_module(&mut self, module: &mut Module) {
+        module.body.visit_mut_children_with(self);
+        self.normalize_module_items(&mut module.body, true);
+    }
+
+    fn visit_mut_script(&mut self, script: &mut Script) {
+        script.body.visit_mut_children_with(self);
+        self.normalize_stmts(&mut script.body, true);
+    }
+
+    fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+        items.visit_mut_children_with(self);
+        self.normalize_module_items(items, false);
+    }
+
+    fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
+        stmts.visit_mut_children_with(self);
+        self.normalize_stmts(stmts, false);
+    }
+}
+
+impl EnumIifeNormalizer<'_> {
+    fn normalize_module_items(&self, items: &mut [ModuleItem], top_level: bool) {
+        self.normalize(
+            items,
+            top_level,
+            |item| match item {
+                ModuleItem::Stmt(Stmt::Decl(Decl::Var(var))) => Some(var),
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                    decl: Decl::Var(var),
+                    ..
+                })) => Some(var),
+                _ => None,
+            },
+            ModuleItem::as_mut_stmt,
+        );
+    }
+
+    fn normalize_stmts(&self, stmts: &mut [Stmt], top_level: bool) {
+        self.normalize(
+            stmts,
+            top_level,
+            |stmt| match stmt {
+                Stmt::Decl(Decl::Var(var)) => Some(var),
+                _ => None,
+            },
+            |stmt| Some(stmt),
+        );
+    }
+}
+
+/// The single argument of `(function(Role) { ... })(arg)`.
+fn iife_arg(expr: &mut Expr) -> Option<&mut Box<Expr>> {
+    let Expr::Call(call) = expr else {
+        return None;
+    };
+    let Callee::Expr(callee) = &call.callee else {
+        return None;
+    };
+    let callee = if let Expr::Paren(paren) = &**callee {
+        &*paren.expr
+    } else {
+        &**callee
+    };
+    if !matches!(callee, Expr::Fn(_) | Expr::Arrow(_)) {
+        return None;
+    }
+
+    match call.args.as_mut_slice() {
+        [ExprOrSpread { spread: None, expr }] => Some(expr),
+        _ => None,
+    }
+}
+
+/// `Role || (Role = {})` -> `Role`
+fn guarded_target(expr: &Expr) -> Option<Id> {
+    let Expr::Bin(BinExpr {
+        op: BinaryOp::LogicalOr,
+        left,
+        right,
+        ..
+    }) = expr
+    else {
+        return None;
+    };
+    let Expr::Ident(target) = &**left else {
+        return None;
+    };
+    let assigned = empty_object_assign_target(right)?;
+    let SimpleAssignTarget::Ident(binding) = assigned.as_simple()? else {
+        return None;
+    };
+    (binding.id.to_id() == target.to_id()).then(|| target.to_id())
+}
+
+/// `A.B || (A.B = {})`
+fn is_member_guard(expr: &Expr) -> bool {
+    let Expr::Bin(BinExpr {
+        op: BinaryOp::LogicalOr,
+        left,
+        right,
+        ..
+    }) = expr
+    else {
+        return false;
+    };
+    matches!(&**left, Expr::Member(_))
+        && matches!(
+            empty_object_assign_target(right).and_then(AssignTarget::as_simple),
+            Some(SimpleAssignTarget::Member(_))
+        )
+}
+
+/// The target of `(x = {})`.
+fn empty_object_assign_target(expr: &Expr) -> Option<&AssignTarget> {
+    let Expr::Paren(paren) = expr else {
+        return None;
+    };
+    let Expr::Assign(assign) = &*paren.expr else {
+        return None;
+    };
+    let Expr::Object(object) = &*assign.right else {
+        return None;
+    };
+    (assign.op == AssignOp::Assign && object.props.is_empty()).then_some(&assign.left)
+}
+
+/// `Role || (Role = {})` -> `Role = {}`
+fn strip_enum_guard(expr: &mut Box<Expr>) {
+    if let Expr::Bin(bin) = &mut **expr {
+        if let Expr::Paren(paren) = &mut *bin.right {
+            *expr = paren.expr.take();
+        }
+    }
+}
+
+/// Replace or hint the getter functions CommonJS barrels re-export through.
+///
+/// Before: `Object.defineProperty(exports, "X", { enumerable: true, get: function() { return _x.X; } })`
+/// After:  `Object.defineProperty(exports, "X", { enumerable: true, get: Reflect.get.bind(null, _x, "X") })`
+///
+/// SWC's `_export(exports, { X: function() { return _x.X; } })` is handled the same way.
+/// Binding reads the namespace right away, so it is only done when the namespace is
+/// initialized by an earlier statement. SWC emits `_export` ahead of its `require` calls,
+/// so its getters, those that return a local binding (`return X;`) and every getter in
+/// `mode: "ignoreHints"` get an ignore hint instead.
+pub fn normalize_reexport_getters(
+    program: &mut Program,
+    bindings: &ModuleBindings,
+    rewrite: bool,
+    hints: Option<&IgnoreHints>,
+) {
+    let rewriter = ReexportGetterRewriter {
+        bindings,
+        rewrite,
+        hints,
+    };
+    if let Program::Module(module) = program {
+        for (index, item) in module.body.iter_mut().enumerate() {
+            if let ModuleItem::Stmt(stmt) = item {
+                rewriter.normalize_stmt(stmt, index);
+            }
+        }
+    } else if let Program::Script(script) = program {
+        for (index, stmt) in script.body.iter_mut().enumerate() {
+            rewriter.normalize_stmt(stmt, index);
+        }
+    }
+}
+
+struct ReexportGetterRewriter<'a> {
+    bindings: &'a ModuleBindings,
+    rewrite: bool,
+    hints: Option<&'a IgnoreHints<'a>>,
+}
+
+impl ReexportGetterRewriter<'_> {
+    fn normalize_stmt(&self, stmt: &mut Stmt, index: usize) {
+        let Stmt::Expr(ExprStmt { expr, .. }) = stmt else {
+            return;
+        };
+        let Expr::Call(call) = &mut **expr else {
+            return;
+        };
+        let Some(ExprOrSpread { expr: target, .. }) = call.args.first() else {
+            return;
+        };
+        if !matches!(&**target, Expr::Ident(target) if &*target.sym == "exports") {
+            return;
+        }
+
+        if is_object_define_property(&call.callee) {
+            // `Object.defineProperty(exports, "X", { get: function() { ... } })`
+            let Some(Expr::Object(descriptor)) = call.args.get_mut(2).map(|arg| &mut *arg.expr)
+            else {
+                return;
+            };
+            for prop in &mut descriptor.props {
+                if let Some(getter) = key_value(prop, "get") {
+                    self.normalize(getter, index);
+                }
+            }
+        } else if is_export_helper(&call.callee) {
+            // `_export(exports, { X: function() { ... } })`
+            let Some(Expr::Object(getters)) = call.args.get_mut(1).map(|arg| &mut *arg.expr) else {
+                return;
+            };
+            for prop in &mut getters.props {
+                if let PropOrSpread::Prop(prop) = prop {
+                    if let Prop::KeyValue(kv) = &mut **prop {
+                        self.normalize(&mut kv.value, index);
+                    }
+                }
+            }
+        }
+    }
+
+    fn normalize(&self, getter: &mut Box<Expr>, index: usize) {
+        let Some(returned) = getter_return(getter) else {
+            return;
+        };
+        let bound = if self.rewrite {
+            bound_member_lookup(returned, getter.span())
+        } else {
+            None
+        };
+        let initialized = bound.as_ref().is_some_and(|bound| {
+            referenced_idents(bound).iter().all(|name| {
+                self.bindings
+                    .declaration_index(name)
+                    .is_none_or(|declared| declared < index)
+            })
+        });
+
+        if let (Some(bound), true) = (bound, initialized) {
+            **getter = bound;
+        } else if let Some(hints) = self.hints {
+            hints.ignore(getter.span());
+        }
+    }
+}
+
+fn is_object_define_property(callee: &Callee) -> bool {
+    let Some(Expr::Member(MemberExpr {
+        obj,
+        prop: MemberProp::Ident(prop),
+        ..
+    })) = callee.as_expr().map(|callee| &**callee)
+    else {
+        return false;
+    };
+    matches!(&**obj, Expr::Ident(obj) if &*obj.sym == "Object") && &*prop.sym == "defineProperty"
+}
+
+fn is_export_helper(callee: &Callee) -> bool {
+    matches!(
+        callee.as_expr().map(|callee| &**callee),
+        Some(Expr::Ident(ident)) if is_renamed_from(&ident.sym, "_export")
+    )
+}
+
+/// The value of the `key: value` property named `key`.
+fn key_value<'a>(prop: &'a mut PropOrSpread, key: &str) -> Option<&'a mut Box<Expr>> {
+    let PropOrSpread::Prop(prop) = prop else {
+        return None;
+    };
+    let Prop::KeyValue(kv) = &mut **prop else {
+        return None;
+    };
+    matches!(&kv.key, PropName::Ident(name) if &*name.sym == key).then_some(&mut kv.value)
+}
+
+/// The expression returned by a getter of the form `function() { return expr; }`.
+fn getter_return(getter: &Expr) -> Option<&Expr> {
+    let Expr::Fn(FnExpr { function, .. }) = getter else {
+        return None;
+    };
+    if !function.params.is_empty() || function.is_async || function.is_generator {
+        return None;
+    }
+    match function.body.as_ref()?.stmts.as_slice() {
+        [Stmt::Return(ReturnStmt { arg: Some(arg), .. })] => Some(arg),
+        _ => None,
+    }
+}
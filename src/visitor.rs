@@ -1,54 +1,557 @@
+use std::collections::HashSet;
+
+use swc_core::atoms::Atom;
+use swc_core::common::comments::{Comments, NoopComments};
+use swc_core::common::errors::{SourceMapper, HANDLER};
+use swc_core::common::sync::Lrc;
+use swc_core::common::Span;
 use swc_core::ecma::ast::*;
 use swc_core::ecma::visit::{VisitMut, VisitMutWith};
 
+use crate::bindings::ModuleBindings;
 use crate::detection::*;
+use crate::hints::IgnoreHints;
 use crate::transforms::*;
-use crate::Config;
+use crate::{
+    Config, CoverageProvider, Mode, SimplifyTo, SimplifyTypeofs, TypeofKeyPreset, UnwrapStrategy,
+    UnwrapTypeArrows,
+};
 
-pub struct DecoratorCoverageVisitor {
+pub struct DecoratorCoverageVisitor<C: Comments = NoopComments> {
     config: Config,
+    /// Comment storage for `mode: "ignoreHints"`. Without it that mode leaves code untouched.
+    comments: Option<C>,
+    /// Source positions, used to tell single-line nodes apart for c8 hints.
+    source_map: Option<Lrc<dyn SourceMapper>>,
+    helpers: HelperResolver,
+    registry: ThunkRegistry,
+    /// Option property names `unwrapTypeArrows` looks for.
+    type_keys: Vec<String>,
+    /// `_ts_metadata` keys whose typeof guards are simplified.
+    typeof_keys: Vec<String>,
+    bindings: ModuleBindings,
+    /// Index of the top-level item being visited.
+    item_index: Option<usize>,
+    /// tsc guard temporaries (`_a`, `_b`) released by simplified guards.
+    released_temps: HashSet<Atom>,
+    /// Temporaries holding TC39 decorators (`_dec`, `_dec1`) referenced by `_apply_decs_*`
+    /// calls, and the classes they decorate.
+    decorator_temps: ApplyDecsTemps,
+    /// Whether custom `simplifyTypeofs` keys were reported as ineffective on decorator nodes.
+    reported_custom_keys: bool,
 }
 
 impl DecoratorCoverageVisitor {
     pub fn new(config: Config) -> Self {
-        Self { config }
+        Self::with_comments(config, None)
     }
 }
 
-impl VisitMut for DecoratorCoverageVisitor {
+impl<C: Comments> DecoratorCoverageVisitor<C> {
+    pub fn with_comments(config: Config, comments: Option<C>) -> Self {
+        let helpers = HelperResolver::new(&ModuleBindings::default(), config.helper_names.as_ref());
+        let registry = ThunkRegistry::new(config.preserve_thunks.as_deref());
+        let type_keys = config
+            .unwrap_type_arrows
+            .as_ref()
+            .map_or_else(|| vec!["type".to_string()], UnwrapTypeArrows::keys);
+        let mut typeof_keys = config
+            .simplify_typeofs
+            .as_ref()
+            .map(SimplifyTypeofs::keys)
+            .unwrap_or_default();
+        if config.simplify_metadata_typeofs.unwrap_or(true) {
+            typeof_keys.push(TypeofKeyPreset::Paramtypes.key().to_string());
+        }
+        if config.simplify_design_type_typeofs.unwrap_or(false) {
+            typeof_keys.push(TypeofKeyPreset::DesignType.key().to_string());
+        }
+        Self {
+            config,
+            comments,
+            source_map: None,
+            helpers,
+            registry,
+            type_keys,
+            typeof_keys,
+            bindings: ModuleBindings::default(),
+            item_index: None,
+            released_temps: HashSet::new(),
+            decorator_temps: ApplyDecsTemps::default(),
+            reported_custom_keys: false,
+        }
+    }
+
+    /// Whether typeof guards in `_ts_metadata` calls with this key are simplified.
+    fn simplifies_typeofs(&self, key: TypeofKeyPreset) -> bool {
+        self.typeof_keys.iter().any(|k| k == key.key())
+    }
+
+    pub fn with_source_map(mut self, source_map: Lrc<dyn SourceMapper>) -> Self {
+        self.source_map = Some(source_map);
+        self
+    }
+
+    fn is_hint_mode(&self) -> bool {
+        self.config.mode.unwrap_or_default() == Mode::IgnoreHints
+    }
+
+    /// The coverage-ignore sink, when the host provides comment storage.
+    fn ignore_hints(&self) -> Option<IgnoreHints<'_>> {
+        let provider = self.config.coverage_provider.unwrap_or_default();
+        self.comments
+            .as_ref()
+            .map(|comments| IgnoreHints::new(comments, provider, self.source_map.as_deref()))
+    }
+
+    /// `None` when `unwrapTypeArrows` is off.
+    fn type_prop_search(&self) -> Option<TypePropSearch<'_>> {
+        let enabled = self
+            .config
+            .unwrap_type_arrows
+            .as_ref()
+            .is_none_or(UnwrapTypeArrows::is_enabled);
+        enabled.then(|| TypePropSearch {
+            keys: &self.type_keys,
+            depth: self.config.type_arrow_depth.unwrap_or(4),
+        })
+    }
+
+    fn thunk_policy<'a>(&'a self, hints: Option<&'a IgnoreHints<'a>>) -> ThunkPolicy<'a> {
+        let provider = self.config.coverage_provider.unwrap_or_default();
+        ThunkPolicy {
+            unwrap: !self.is_hint_mode(),
+            hints,
+            hint_kept: self.is_hint_mode() || provider == CoverageProvider::V8,
+            bindings: &self.bindings,
+            item_index: self.item_index,
+            before_class: None,
+            registry: &self.registry,
+            lazy: self.config.unwrap_strategy.unwrap_or_default() == UnwrapStrategy::Lazy,
+            conservative: self.config.conservative_unwrap.unwrap_or(false),
+        }
+    }
+
+    /// Apply the unwrap policies to a single decorator expression outside a `_ts_decorate` array.
+    fn unwrap_decorator_expr(&self, decorator: &mut Box<Expr>) {
+        self.unwrap_decorator_expr_with(decorator, false);
+    }
+
+    /// `before_class`: the expression initializes a `_dec` temp, which runs before its class
+    /// is initialized. See [`ThunkPolicy::before_class`].
+    fn unwrap_decorator_expr_with(&self, decorator: &mut Box<Expr>, before_class: bool) {
+        let hints = self.ignore_hints();
+        let policy = ThunkPolicy {
+            before_class: before_class.then_some(&self.decorator_temps.classes),
+            ..self.thunk_policy(hints.as_ref())
+        };
+
+        if self.config.unwrap_decorator_arrows.unwrap_or(true) {
+            rewrite_forward_refs(decorator, &policy);
+            unwrap_arrows_in_call_args(decorator, &policy);
+        }
+
+        if let Some(search) = self.type_prop_search() {
+            unwrap_type_props_in_expr(decorator, &policy, &search);
+        }
+    }
+
+    /// `mode: "ignoreHints"` counterpart of the `_ts_decorate` array rewrites.
+    fn hint_decorate_array(&self, elems: &mut [Option<ExprOrSpread>]) {
+        let Some(hints) = self.ignore_hints() else {
+            return;
+        };
+        let policy = self.thunk_policy(Some(&hints));
+
+        if self.config.unwrap_decorator_arrows.unwrap_or(true) {
+            unwrap_decorator_arrow_args(elems, &policy);
+        }
+
+        if let Some(search) = self.type_prop_search() {
+            unwrap_type_arrow_props(elems, &policy, &search);
+        }
+
+        hint_metadata_typeof_guards(elems, &self.helpers, &hints);
+    }
+
+    /// The default `_ts_decorate` array rewrites.
+    fn rewrite_decorate_array(
+        &mut self,
+        elems: &mut Vec<Option<ExprOrSpread>>,
+        is_constructor: bool,
+    ) {
+        let keep_design_type = self.config.keep_consumed_design_types.unwrap_or(true)
+            && has_design_type_consumer(elems, &self.bindings);
+
+        if let Some(strip) = self
+            .config
+            .strip_metadata
+            .as_ref()
+            .filter(|strip| strip.is_enabled())
+        {
+            strip_metadata_calls(elems, &self.helpers, &mut self.released_temps, |call| {
+                let key = metadata_key(call);
+                strip.strips(key)
+                    && !(keep_design_type && key == Some("design:type"))
+                    && !(is_constructor && key == Some("design:paramtypes"))
+            });
+        }
+
+        {
+            let hints = self.ignore_hints();
+            let policy = self.thunk_policy(hints.as_ref());
+
+            if self.config.unwrap_decorator_arrows.unwrap_or(true) {
+                unwrap_decorator_arrow_args(elems, &policy);
+            }
+
+            if let Some(search) = self.type_prop_search() {
+                unwrap_type_arrow_props(elems, &policy, &search);
+            }
+        }
+
+        // Constructor paramtypes drive DI, so they only keep guards' real types.
+        let alternate = self.config.simplify_to.unwrap_or_default() == SimplifyTo::Alternate;
+        let validated_params = if self.config.keep_validated_param_types.unwrap_or(true) {
+            validated_param_indices(elems, &self.helpers)
+        } else {
+            Vec::new()
+        };
+        let policy = GuardPolicy {
+            bindings: &self.bindings,
+            item_index: self.item_index,
+            alternate,
+            object_fallback: !is_constructor,
+            validated_params,
+        };
+        let simplify = !is_constructor || alternate;
+
+        if self.simplifies_typeofs(TypeofKeyPreset::Paramtypes) && simplify {
+            simplify_metadata_typeof_guards(
+                elems,
+                &self.helpers,
+                &mut self.released_temps,
+                &policy,
+            );
+        }
+
+        if self.simplifies_typeofs(TypeofKeyPreset::DesignType) && simplify && !keep_design_type {
+            simplify_design_type_typeof_guards(
+                elems,
+                &self.helpers,
+                &mut self.released_temps,
+                &policy,
+            );
+        }
+
+        // Validated positions only apply to `design:paramtypes`.
+        let other_keys: Vec<&str> = self
+            .typeof_keys
+            .iter()
+            .map(String::as_str)
+            .filter(|key| {
+                *key != TypeofKeyPreset::Paramtypes.key()
+                    && *key != TypeofKeyPreset::DesignType.key()
+            })
+            .collect();
+        if !other_keys.is_empty() && simplify {
+            let policy = GuardPolicy {
+                validated_params: Vec::new(),
+                ..policy
+            };
+            simplify_keyed_typeof_guards(
+                elems,
+                &self.helpers,
+                &mut self.released_temps,
+                &policy,
+                &other_keys,
+            );
+        }
+    }
+
+    /// What pre-transform type annotations are simplified to: `object`, or left as they
+    /// are under `simplifyTo: "alternate"` when the module defines the reference.
+    fn type_ref_policy(&self) -> GuardPolicy<'_> {
+        GuardPolicy {
+            bindings: &self.bindings,
+            item_index: self.item_index,
+            alternate: self.config.simplify_to.unwrap_or_default() == SimplifyTo::Alternate,
+            object_fallback: true,
+            validated_params: Vec::new(),
+        }
+    }
+
+    fn visit_decorated_function(&self, function: &mut Function) {
+        let mut decorated = !function.decorators.is_empty();
+
+        for decorator in &mut function.decorators {
+            self.unwrap_decorator_expr(&mut decorator.expr);
+        }
+        for param in &mut function.params {
+            decorated |= !param.decorators.is_empty();
+            for decorator in &mut param.decorators {
+                self.unwrap_decorator_expr(&mut decorator.expr);
+            }
+        }
+
+        // SWC only emits `design:paramtypes` for decorated methods.
+        // Hint mode keeps the real types.
+        if decorated && !self.is_hint_mode() && self.simplifies_typeofs(TypeofKeyPreset::Paramtypes)
+        {
+            let keep_validated = self.config.keep_validated_param_types.unwrap_or(true);
+            simplify_param_type_refs(
+                function.params.iter_mut().filter(|param| {
+                    !(keep_validated
+                        && param
+                            .decorators
+                            .iter()
+                            .any(|decorator| is_validated_param_decorator(&decorator.expr)))
+                }),
+                &self.type_ref_policy(),
+            );
+        }
+        if decorated && !self.is_hint_mode() && self.simplifies_typeofs(TypeofKeyPreset::Returntype)
+        {
+            simplify_type_ref(&mut function.return_type, &self.type_ref_policy());
+        }
+    }
+
+    /// Custom keys name metadata the decorators emit at runtime, which has no annotation
+    /// to rewrite before SWC's decorator pass. Reported once per program.
+    fn report_custom_typeof_keys(&mut self, span: Span) {
+        if self.reported_custom_keys || !HANDLER.is_set() {
+            return;
+        }
+        let presets = [
+            TypeofKeyPreset::Paramtypes,
+            TypeofKeyPreset::DesignType,
+            TypeofKeyPreset::Returntype,
+        ];
+        let custom: Vec<&str> = self
+            .typeof_keys
+            .iter()
+            .map(String::as_str)
+            .filter(|key| presets.iter().all(|preset| preset.key() != *key))
+            .collect();
+        if custom.is_empty() {
+            return;
+        }
+
+        self.reported_custom_keys = true;
+        HANDLER.with(|handler| {
+            handler.span_warn(
+                span,
+                &format!(
+                    "simplifyTypeofs keys have no effect before SWC's decorator pass: {}",
+                    custom.join(", ")
+                ),
+            );
+        });
+    }
+}
+
+/// Whether the class or any of its members carries a decorator node.
+fn has_decorator_nodes(class: &Class) -> bool {
+    !class.decorators.is_empty()
+        || class.body.iter().any(|member| match member {
+            ClassMember::Constructor(ctor) => ctor.params.iter().any(|param| {
+                if let ParamOrTsParamProp::Param(param) = param {
+                    !param.decorators.is_empty()
+                } else if let ParamOrTsParamProp::TsParamProp(prop) = param {
+                    !prop.decorators.is_empty()
+                } else {
+                    false
+                }
+            }),
+            ClassMember::Method(ClassMethod { function, .. })
+            | ClassMember::PrivateMethod(PrivateMethod { function, .. }) => {
+                !function.decorators.is_empty()
+                    || function
+                        .params
+                        .iter()
+                        .any(|param| !param.decorators.is_empty())
+            }
+            ClassMember::ClassProp(ClassProp { decorators, .. })
+            | ClassMember::PrivateProp(PrivateProp { decorators, .. })
+            | ClassMember::AutoAccessor(AutoAccessor { decorators, .. }) => !decorators.is_empty(),
+            _ => false,
+        })
+}
+
+impl<C: Comments> VisitMut for DecoratorCoverageVisitor<C> {
+    fn visit_mut_program(&mut self, program: &mut Program) {
+        if self.config.externalize_helpers.unwrap_or(false) {
+            externalize_inline_helpers(program);
+        }
+
+        self.bindings = ModuleBindings::collect(program);
+        self.helpers = HelperResolver::new(&self.bindings, self.config.helper_names.as_ref());
+        self.decorator_temps = collect_apply_decs_temps(program, &self.helpers);
+
+        if self.config.ignore_helpers.unwrap_or(false) {
+            if let Some(hints) = self.ignore_hints() {
+                hint_inline_helpers(program, &self.helpers, &hints);
+            }
+        }
+
+        if self.config.normalize_enum_iifes.unwrap_or(false) {
+            let hints = self.ignore_hints();
+            normalize_enum_iifes(
+                program,
+                &self.bindings,
+                !self.is_hint_mode(),
+                hints.as_ref(),
+            );
+        }
+
+        if self.config.normalize_reexport_getters.unwrap_or(false) {
+            let hints = self.ignore_hints();
+            normalize_reexport_getters(
+                program,
+                &self.bindings,
+                !self.is_hint_mode(),
+                hints.as_ref(),
+            );
+        }
+
+        program.visit_mut_children_with(self);
+
+        remove_unused_temp_vars(program, &self.released_temps);
+    }
+
+    fn visit_mut_module(&mut self, module: &mut Module) {
+        for (index, item) in module.body.iter_mut().enumerate() {
+            self.item_index = Some(index);
+            item.visit_mut_with(self);
+        }
+        self.item_index = None;
+    }
+
+    fn visit_mut_script(&mut self, script: &mut Script) {
+        for (index, stmt) in script.body.iter_mut().enumerate() {
+            self.item_index = Some(index);
+            stmt.visit_mut_with(self);
+        }
+        self.item_index = None;
+    }
+
     fn visit_mut_call_expr(&mut self, call: &mut CallExpr) {
-        if is_ts_decorate_call(call) {
+        if is_ts_decorate_call(call, &self.helpers) {
             let is_constructor = is_constructor_decorate(call);
 
             if let Some(ExprOrSpread { expr, .. }) = call.args.first_mut() {
                 if let Expr::Array(array) = &mut **expr {
-                    if self.config.strip_metadata.unwrap_or(false) {
-                        strip_metadata_calls(&mut array.elems);
+                    if self.is_hint_mode() {
+                        self.hint_decorate_array(&mut array.elems);
+                    } else {
+                        self.rewrite_decorate_array(&mut array.elems, is_constructor);
                     }
+                }
+            }
+        }
 
-                    if self.config.unwrap_decorator_arrows.unwrap_or(true) {
-                        unwrap_decorator_arrow_args(&mut array.elems);
-                    }
+        if is_apply_decs_call(call, &self.helpers) {
+            for_each_apply_decs_decorator(call, &mut |decorator| {
+                self.unwrap_decorator_expr(decorator)
+            });
+        }
 
-                    if self.config.unwrap_type_arrows.unwrap_or(true) {
-                        unwrap_type_arrow_props(&mut array.elems);
-                    }
+        call.visit_mut_children_with(self);
+    }
 
-                    if self.config.simplify_metadata_typeofs.unwrap_or(true)
-                        && !is_constructor
-                    {
-                        simplify_metadata_typeof_guards(&mut array.elems);
-                    }
+    /// Pre-transform path: with `jsc.experimental.runPluginFirst` the plugin sees
+    /// `Decorator` nodes instead of `_ts_decorate` arrays. Programs without decorator
+    /// nodes pass through untouched, so both paths can stay active at once.
+    fn visit_mut_class(&mut self, class: &mut Class) {
+        if let Some(hints) = self.ignore_hints() {
+            hint_generated_accessors(class, &self.helpers, &hints);
+        }
+        if has_decorator_nodes(class) {
+            self.report_custom_typeof_keys(class.span);
+        }
+
+        for decorator in &mut class.decorators {
+            self.unwrap_decorator_expr(&mut decorator.expr);
+        }
 
-                    if self.config.simplify_design_type_typeofs.unwrap_or(false)
-                        && !is_constructor
+        for member in &mut class.body {
+            match member {
+                ClassMember::Constructor(ctor) => {
+                    // Constructor paramtypes drive DI, so only decorators are rewritten.
+                    for param in &mut ctor.params {
+                        let decorators = if let ParamOrTsParamProp::Param(param) = param {
+                            &mut param.decorators
+                        } else if let ParamOrTsParamProp::TsParamProp(prop) = param {
+                            &mut prop.decorators
+                        } else {
+                            continue;
+                        };
+                        for decorator in decorators {
+                            self.unwrap_decorator_expr(&mut decorator.expr);
+                        }
+                    }
+                }
+                ClassMember::Method(ClassMethod { function, .. })
+                | ClassMember::PrivateMethod(PrivateMethod { function, .. }) => {
+                    self.visit_decorated_function(function);
+                }
+                ClassMember::ClassProp(ClassProp {
+                    decorators,
+                    type_ann,
+                    ..
+                })
+                | ClassMember::PrivateProp(PrivateProp {
+                    decorators,
+                    type_ann,
+                    ..
+                })
+                | ClassMember::AutoAccessor(AutoAccessor {
+                    decorators,
+                    type_ann,
+                    ..
+                }) => {
+                    // Before unwrapping, so `Field(() => String)` still names its type.
+                    let consumed = self.config.keep_consumed_design_types.unwrap_or(true)
+                        && decorators.iter().any(|decorator| {
+                            is_design_type_consumer(&decorator.expr, &self.bindings)
+                        });
+                    for decorator in decorators.iter_mut() {
+                        self.unwrap_decorator_expr(&mut decorator.expr);
+                    }
+                    if !decorators.is_empty()
+                        && !consumed
+                        && !self.is_hint_mode()
+                        && self.simplifies_typeofs(TypeofKeyPreset::DesignType)
                     {
-                        simplify_design_type_typeof_guards(&mut array.elems);
+                        simplify_type_ref(type_ann, &self.type_ref_policy());
                     }
                 }
+                _ => {}
             }
         }
 
-        call.visit_mut_children_with(self);
+        class.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_assign_expr(&mut self, assign: &mut AssignExpr) {
+        // `_dec = (0, _graphql.Field)(() => String)`
+        if let AssignTarget::Simple(SimpleAssignTarget::Ident(binding)) = &assign.left {
+            if self.decorator_temps.temps.contains(&binding.id.sym) {
+                self.unwrap_decorator_expr_with(&mut assign.right, true);
+            }
+        }
+
+        assign.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_var_declarator(&mut self, decl: &mut VarDeclarator) {
+        // `let _dec = (0, _graphql.Field)(() => String)`
+        if let (Pat::Ident(binding), Some(init)) = (&decl.name, &mut decl.init) {
+            if self.decorator_temps.temps.contains(&binding.id.sym) {
+                self.unwrap_decorator_expr_with(init, true);
+            }
+        }
+
+        decl.visit_mut_children_with(self);
     }
 }
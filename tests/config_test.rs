@@ -1,4 +1,12 @@
-use swc_jest_coverage_nestjs_plugin::{Config, OverrideRule, PluginConfig};
+use swc_jest_coverage_nestjs_plugin::{
+    Config, CoverageProvider, Mode, OverrideRule, PluginConfig, SimplifyTo, StripMetadata,
+    TypeKeyPreset, TypeofKeyPreset, UnwrapStrategy, UnwrapTypeArrows,
+};
+
+/// A config with every option unset, as produced by deserializing `{}`.
+fn unset() -> Config {
+    serde_json::from_str("{}").unwrap()
+}
 
 fn base_config(simplify_design_type: bool) -> PluginConfig {
     PluginConfig {
@@ -14,11 +22,8 @@ fn override_rule(patterns: &[&str], simplify_design_type: Option<bool>) -> Overr
     OverrideRule {
         files: patterns.iter().map(|s| s.to_string()).collect(),
         config: Config {
-            unwrap_type_arrows: None,
-            strip_metadata: None,
-            unwrap_decorator_arrows: None,
-            simplify_metadata_typeofs: None,
             simplify_design_type_typeofs: simplify_design_type,
+            ..unset()
         },
     }
 }
@@ -40,7 +45,7 @@ fn override_matches_and_merges() {
     let resolved = pc.resolve(Some("/src/models/venue.model.ts"));
     assert_eq!(resolved.simplify_design_type_typeofs, Some(true));
     // Other fields inherited from base
-    assert_eq!(resolved.unwrap_type_arrows, Some(true));
+    assert_eq!(resolved.unwrap_type_arrows, Some(true.into()));
 }
 
 #[test]
@@ -96,11 +101,8 @@ fn override_explicit_false_beats_base_true() {
     pc.overrides.push(OverrideRule {
         files: vec!["**/special.*".to_string()],
         config: Config {
-            unwrap_type_arrows: None,
-            strip_metadata: None,
-            unwrap_decorator_arrows: None,
             simplify_metadata_typeofs: Some(false),
-            simplify_design_type_typeofs: None,
+            ..unset()
         },
     });
     let resolved = pc.resolve(Some("/src/special.ts"));
@@ -115,17 +117,14 @@ fn multiple_overrides_only_matching_one_applies() {
     pc.overrides.push(OverrideRule {
         files: vec!["**/services/**".to_string()],
         config: Config {
-            unwrap_type_arrows: None,
-            strip_metadata: Some(true),
-            unwrap_decorator_arrows: None,
-            simplify_metadata_typeofs: None,
-            simplify_design_type_typeofs: None,
+            strip_metadata: Some(true.into()),
+            ..unset()
         },
     });
     // Only first override matches
     let resolved = pc.resolve(Some("/src/models/venue.model.ts"));
     assert_eq!(resolved.simplify_design_type_typeofs, Some(true));
-    assert_eq!(resolved.strip_metadata, Some(false)); // inherited from base, not second override
+    assert_eq!(resolved.strip_metadata, Some(false.into())); // inherited from base, not second override
 }
 
 #[test]
@@ -138,17 +137,14 @@ fn multiple_overrides_with_different_fields() {
     pc.overrides.push(OverrideRule {
         files: vec!["**/models/**".to_string()],
         config: Config {
-            unwrap_type_arrows: None,
-            strip_metadata: Some(true),
-            unwrap_decorator_arrows: None,
-            simplify_metadata_typeofs: None,
-            simplify_design_type_typeofs: None,
+            strip_metadata: Some(true.into()),
+            ..unset()
         },
     });
     let resolved = pc.resolve(Some("/src/models/venue.model.ts"));
     // Both overrides applied
     assert_eq!(resolved.simplify_design_type_typeofs, Some(true));
-    assert_eq!(resolved.strip_metadata, Some(true));
+    assert_eq!(resolved.strip_metadata, Some(true.into()));
 }
 
 // --- Merge behavior ---
@@ -160,8 +156,8 @@ fn override_only_specified_fields() {
         .push(override_rule(&["**/*.model.*"], Some(true)));
     let resolved = pc.resolve(Some("/src/models/venue.model.ts"));
     assert_eq!(resolved.simplify_design_type_typeofs, Some(true));
-    assert_eq!(resolved.unwrap_type_arrows, Some(true));
-    assert_eq!(resolved.strip_metadata, Some(false));
+    assert_eq!(resolved.unwrap_type_arrows, Some(true.into()));
+    assert_eq!(resolved.strip_metadata, Some(false.into()));
     assert_eq!(resolved.unwrap_decorator_arrows, Some(true));
     assert_eq!(resolved.simplify_metadata_typeofs, Some(true));
 }
@@ -171,17 +167,11 @@ fn override_with_all_none_fields_is_noop() {
     let mut pc = base_config(true);
     pc.overrides.push(OverrideRule {
         files: vec!["**/*".to_string()],
-        config: Config {
-            unwrap_type_arrows: None,
-            strip_metadata: None,
-            unwrap_decorator_arrows: None,
-            simplify_metadata_typeofs: None,
-            simplify_design_type_typeofs: None,
-        },
+        config: unset(),
     });
     let resolved = pc.resolve(Some("/src/anything.ts"));
-    assert_eq!(resolved.unwrap_type_arrows, Some(true));
-    assert_eq!(resolved.strip_metadata, Some(false));
+    assert_eq!(resolved.unwrap_type_arrows, Some(true.into()));
+    assert_eq!(resolved.strip_metadata, Some(false.into()));
     assert_eq!(resolved.unwrap_decorator_arrows, Some(true));
     assert_eq!(resolved.simplify_metadata_typeofs, Some(true));
     assert_eq!(resolved.simplify_design_type_typeofs, Some(true));
@@ -331,3 +321,218 @@ fn full_config_deserialization() {
         Some(false)
     );
 }
+
+#[test]
+fn helper_names_deserialization() {
+    let json = r#"{ "helperNames": { "decorate": ["__myDecorate"], "param": ["__myParam"] } }"#;
+    let pc: PluginConfig = serde_json::from_str(json).unwrap();
+    let names = pc.base.helper_names.unwrap();
+    assert_eq!(names.decorate, vec!["__myDecorate".to_string()]);
+    assert!(names.metadata.is_empty());
+    assert_eq!(names.param, vec!["__myParam".to_string()]);
+}
+
+#[test]
+fn helper_names_override_replaces_base() {
+    let json = r#"{
+        "helperNames": { "decorate": ["__baseDecorate"] },
+        "overrides": [
+            {
+                "files": ["**/legacy/**"],
+                "config": { "helperNames": { "metadata": ["__legacyMetadata"] } }
+            }
+        ]
+    }"#;
+    let pc: PluginConfig = serde_json::from_str(json).unwrap();
+
+    let legacy = pc
+        .resolve(Some("/src/legacy/user.ts"))
+        .helper_names
+        .unwrap();
+    assert!(legacy.decorate.is_empty());
+    assert_eq!(legacy.metadata, vec!["__legacyMetadata".to_string()]);
+
+    let other = pc.resolve(Some("/src/user.ts")).helper_names.unwrap();
+    assert_eq!(other.decorate, vec!["__baseDecorate".to_string()]);
+}
+
+#[test]
+fn mode_deserialization_and_override() {
+    let json = r#"{
+        "overrides": [
+            { "files": ["**/circular/**"], "config": { "mode": "ignoreHints" } }
+        ]
+    }"#;
+    let pc: PluginConfig = serde_json::from_str(json).unwrap();
+    assert_eq!(pc.base.mode, None);
+    assert_eq!(
+        pc.resolve(Some("/src/circular/user.model.ts")).mode,
+        Some(Mode::IgnoreHints)
+    );
+    assert_eq!(Config::default().mode, Some(Mode::Rewrite));
+}
+
+#[test]
+fn coverage_provider_deserialization() {
+    let pc: PluginConfig = serde_json::from_str(r#"{ "coverageProvider": "v8" }"#).unwrap();
+    assert_eq!(pc.base.coverage_provider, Some(CoverageProvider::V8));
+
+    let pc: PluginConfig = serde_json::from_str(r#"{ "coverageProvider": "istanbul" }"#).unwrap();
+    assert_eq!(pc.base.coverage_provider, Some(CoverageProvider::Istanbul));
+}
+
+#[test]
+fn preserve_thunks_deserialization() {
+    let json =
+        r#"{ "preserveThunks": [{ "callee": "Lazy", "args": [1] }, { "callee": "defer" }] }"#;
+    let pc: PluginConfig = serde_json::from_str(json).unwrap();
+    let entries = pc.base.preserve_thunks.unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].callee, "Lazy");
+    assert_eq!(entries[0].args, vec![1]);
+    assert!(entries[1].args.is_empty());
+}
+
+#[test]
+fn unwrap_strategy_override() {
+    let json = r#"{
+        "unwrapStrategy": "eager",
+        "overrides": [
+            { "files": ["**/entities/**"], "config": { "unwrapStrategy": "lazy" } }
+        ]
+    }"#;
+    let pc: PluginConfig = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        pc.resolve(Some("/src/entities/photo.entity.ts"))
+            .unwrap_strategy,
+        Some(UnwrapStrategy::Lazy)
+    );
+    assert_eq!(
+        pc.resolve(Some("/src/photo.resolver.ts")).unwrap_strategy,
+        Some(UnwrapStrategy::Eager)
+    );
+}
+
+/// A boolean option's camelCase key, field and default.
+type BoolOption = (&'static str, fn(&Config) -> Option<bool>, bool);
+
+#[test]
+fn boolean_option_defaults() {
+    let options: &[BoolOption] = &[
+        ("conservativeUnwrap", |c| c.conservative_unwrap, false),
+        ("ignoreHelpers", |c| c.ignore_helpers, false),
+        ("externalizeHelpers", |c| c.externalize_helpers, false),
+        ("normalizeEnumIifes", |c| c.normalize_enum_iifes, false),
+        (
+            "normalizeReexportGetters",
+            |c| c.normalize_reexport_getters,
+            false,
+        ),
+        (
+            "keepValidatedParamTypes",
+            |c| c.keep_validated_param_types,
+            true,
+        ),
+        (
+            "keepConsumedDesignTypes",
+            |c| c.keep_consumed_design_types,
+            true,
+        ),
+    ];
+
+    for (key, field, default) in options {
+        assert_eq!(field(&Config::default()), Some(*default), "{key}");
+        assert_eq!(field(&unset()), None, "{key}");
+        let json = format!(r#"{{ "{key}": {} }}"#, !default);
+        let pc: PluginConfig = serde_json::from_str(&json).unwrap();
+        assert_eq!(field(&pc.resolve(None)), Some(!default), "{key}");
+    }
+}
+
+#[test]
+fn type_arrow_depth_override() {
+    let json =
+        r#"{ "overrides": [{ "files": ["**/legacy/**"], "config": { "typeArrowDepth": 1 } }] }"#;
+    let pc: PluginConfig = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        pc.resolve(Some("/src/legacy/a.ts")).type_arrow_depth,
+        Some(1)
+    );
+    assert_eq!(
+        PluginConfig::default().resolve(None).type_arrow_depth,
+        Some(4)
+    );
+}
+
+#[test]
+fn externalize_helpers_override() {
+    let json = r#"{ "externalizeHelpers": true, "overrides": [{ "files": ["**/e2e/**"], "config": { "externalizeHelpers": false } }] }"#;
+    let pc: PluginConfig = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        pc.resolve(Some("/src/a.ts")).externalize_helpers,
+        Some(true)
+    );
+    assert_eq!(
+        pc.resolve(Some("/test/e2e/a.ts")).externalize_helpers,
+        Some(false)
+    );
+    assert_eq!(Config::default().externalize_helpers, Some(false));
+}
+
+#[test]
+fn simplify_to_deserialization() {
+    assert_eq!(Config::default().simplify_to, Some(SimplifyTo::Object));
+    let pc: PluginConfig = serde_json::from_str(r#"{ "simplifyTo": "alternate" }"#).unwrap();
+    assert_eq!(pc.resolve(None).simplify_to, Some(SimplifyTo::Alternate));
+    assert!(serde_json::from_str::<PluginConfig>(r#"{ "simplifyTo": "any" }"#).is_err());
+}
+
+#[test]
+fn unwrap_type_arrows_accepts_bool_or_object() {
+    let pc: PluginConfig = serde_json::from_str(r#"{ "unwrapTypeArrows": false }"#).unwrap();
+    let option = pc.base.unwrap_type_arrows.unwrap();
+    assert!(!option.is_enabled());
+    assert_eq!(option.keys(), vec!["type".to_string()]);
+
+    let json = r#"{ "unwrapTypeArrows": { "presets": ["mikroOrm"], "keys": ["itemType"] } }"#;
+    let pc: PluginConfig = serde_json::from_str(json).unwrap();
+    let option = pc.base.unwrap_type_arrows.unwrap();
+    assert!(option.is_enabled());
+    assert_eq!(option.keys(), vec!["entity", "itemType", "type"]);
+    let UnwrapTypeArrows::Options(options) = option else {
+        panic!("expected the object form");
+    };
+    assert_eq!(options.presets, vec![TypeKeyPreset::MikroOrm]);
+
+    let pc: PluginConfig =
+        serde_json::from_str(r#"{ "unwrapTypeArrows": { "enabled": false } }"#).unwrap();
+    assert!(!pc.base.unwrap_type_arrows.unwrap().is_enabled());
+}
+
+#[test]
+fn strip_metadata_accepts_bool_or_keys() {
+    let json = r#"{ "stripMetadata": ["design:returntype", "design:paramtypes"] }"#;
+    let pc: PluginConfig = serde_json::from_str(json).unwrap();
+    let strip = pc.resolve(None).strip_metadata.unwrap();
+    assert!(strip.is_enabled());
+    assert!(strip.strips(Some("design:returntype")));
+    assert!(!strip.strips(Some("design:type")));
+    assert!(!strip.strips(None));
+
+    let pc: PluginConfig = serde_json::from_str(r#"{ "stripMetadata": [] }"#).unwrap();
+    assert!(!pc.resolve(None).strip_metadata.unwrap().is_enabled());
+    assert!(StripMetadata::from(true).strips(Some("design:type")));
+}
+
+#[test]
+fn simplify_typeofs_keys_and_presets() {
+    let json = r#"{ "simplifyTypeofs": { "presets": ["returntype", "designType"], "keys": ["custom:type", "design:type"] } }"#;
+    let pc: PluginConfig = serde_json::from_str(json).unwrap();
+    let resolved = pc.resolve(None);
+    assert_eq!(
+        resolved.simplify_typeofs.unwrap().keys(),
+        vec!["custom:type", "design:returntype", "design:type"]
+    );
+    assert_eq!(Config::default().simplify_typeofs, None);
+    assert_eq!(TypeofKeyPreset::Paramtypes.key(), "design:paramtypes");
+}
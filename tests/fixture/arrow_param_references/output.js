@@ -0,0 +1,32 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+const _typeorm = require("typeorm");
+const _graphql = require("@nestjs/graphql");
+const _photo = require("./photo.entity");
+function _ts_decorate(decorators, target, key, desc) {
+    var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc, d;
+    if (typeof Reflect === "object" && typeof Reflect.decorate === "function") r = Reflect.decorate(decorators, target, key, desc);
+    else for(var i = decorators.length - 1; i >= 0; i--)if (d = decorators[i]) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(key, r)) || r;
+    return c > 3 && r && Object.defineProperty(target, key, r), r;
+}
+function _ts_metadata(k, v) {
+    if (typeof Reflect === "object" && typeof Reflect.metadata === "function") return Reflect.metadata(k, v);
+}
+class User {
+}
+_ts_decorate([
+    (0, _typeorm.OneToMany)(()=>_photo.Photo, (photo)=>photo.user),
+    (0, _graphql.Field)([
+        _photo.Photo
+    ]),
+    _ts_metadata("design:type", Array)
+], User.prototype, "photos", void 0);
+_ts_decorate([
+    (0, _graphql.Field)(async ()=>_photo.Photo),
+    (0, _graphql.Field)(({ kind })=>[
+            kind
+        ]),
+    _ts_metadata("design:type", Object)
+], User.prototype, "avatar", void 0);
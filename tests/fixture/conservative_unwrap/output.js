@@ -0,0 +1,60 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+const _graphql = require("@nestjs/graphql");
+const _venuemodel = require("./venue.model");
+function _ts_decorate(decorators, target, key, desc) {
+    var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc, d;
+    if (typeof Reflect === "object" && typeof Reflect.decorate === "function") r = Reflect.decorate(decorators, target, key, desc);
+    else for(var i = decorators.length - 1; i >= 0; i--)if (d = decorators[i]) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(key, r)) || r;
+    return c > 3 && r && Object.defineProperty(target, key, r), r;
+}
+function _ts_metadata(k, v) {
+    if (typeof Reflect === "object" && typeof Reflect.metadata === "function") return Reflect.metadata(k, v);
+}
+function _ts_param(paramIndex, decorator) {
+    return function(target, key) {
+        decorator(target, key, paramIndex);
+    };
+}
+class VenueResolver {
+    venues(ids, limit, since) {
+        return [];
+    }
+    name(venue) {
+        return venue.name;
+    }
+}
+_ts_decorate([
+    (0, _graphql.Query)(()=>[
+            _venuemodel.Venue
+        ]),
+    _ts_param(0, (0, _graphql.Args)('ids', {
+        type: [
+            _graphql.ID
+        ]
+    })),
+    _ts_param(1, (0, _graphql.Args)('limit', {
+        type: _graphql.Int
+    })),
+    _ts_param(2, (0, _graphql.Args)('since', {
+        type: Date
+    })),
+    _ts_metadata("design:type", Function),
+    _ts_metadata("design:paramtypes", [
+        Array,
+        Number,
+        Date
+    ]),
+    _ts_metadata("design:returntype", void 0)
+], VenueResolver.prototype, "venues", null);
+_ts_decorate([
+    (0, _graphql.ResolveField)(String),
+    _ts_param(0, (0, _graphql.Parent)()),
+    _ts_metadata("design:type", Function),
+    _ts_metadata("design:paramtypes", [
+        Object
+    ]),
+    _ts_metadata("design:returntype", String)
+], VenueResolver.prototype, "name", null);
@@ -0,0 +1,10 @@
+class MenuResolver {
+    menu() {
+        return null;
+    }
+}
+__decorateClass([
+    (0, _graphql.Query)(()=>Menu),
+    __metadataClass("design:type", Function),
+    __metadataClass("design:paramtypes", [])
+], MenuResolver.prototype, "menu", null);
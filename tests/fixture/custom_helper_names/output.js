@@ -0,0 +1,8 @@
+class MenuResolver {
+    menu() {
+        return null;
+    }
+}
+__decorateClass([
+    (0, _graphql.Query)(Menu)
+], MenuResolver.prototype, "menu", null);
@@ -0,0 +1,39 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+const _graphql = require("@nestjs/graphql");
+const _typeorm = require("typeorm");
+const _swagger = require("@nestjs/swagger");
+const _classvalidator = require("class-validator");
+const _address = require("./address");
+function _ts_decorate(decorators, target, key, desc) {
+    var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc, d;
+    if (typeof Reflect === "object" && typeof Reflect.decorate === "function") r = Reflect.decorate(decorators, target, key, desc);
+    else for(var i = decorators.length - 1; i >= 0; i--)if (d = decorators[i]) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(target, key)) || r;
+    return c > 3 && r && Object.defineProperty(target, key, r), r;
+}
+function _ts_metadata(k, v) {
+    if (typeof Reflect === "object" && typeof Reflect.metadata === "function") return Reflect.metadata(k, v);
+}
+class Venue {
+}
+_ts_decorate([
+    (0, _typeorm.Column)(),
+    _ts_metadata("design:type", String)
+], Venue.prototype, "name", void 0);
+_ts_decorate([
+    (0, _graphql.Field)(),
+    _ts_metadata("design:type", typeof _address.Address === "undefined" ? Object : _address.Address)
+], Venue.prototype, "address", void 0);
+_ts_decorate([
+    (0, _graphql.Field)(_address.Address)
+], Venue.prototype, "billingAddress", void 0);
+_ts_decorate([
+    (0, _swagger.ApiProperty)(),
+    (0, _classvalidator.IsOptional)(),
+    _ts_metadata("design:type", Number)
+], Venue.prototype, "capacity", void 0);
+_ts_decorate([
+    (0, _classvalidator.IsString)()
+], Venue.prototype, "description", void 0);
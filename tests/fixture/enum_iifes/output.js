@@ -0,0 +1,64 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+const _graphql = require("@nestjs/graphql");
+var Role;
+(function(Role) {
+    Role["Admin"] = "admin";
+    Role["User"] = "user";
+})(Role = {});
+(function(Role) {
+    Role["Guest"] = "guest";
+})(/* istanbul ignore next */ Role || (Role = {}));
+var Status;
+(function(Status) {
+    Status[Status["Active"] = 0] = "Active";
+    Status[Status["Disabled"] = 1] = "Disabled";
+})(Status = {});
+(0, _graphql.registerEnumType)(Role, {
+    name: "Role"
+});
+(0, _graphql.registerEnumType)(Status, {
+    name: "Status"
+});
+var Roles;
+(function(Roles) {
+    let Admin;
+    (function(Admin) {
+        Admin.level = 1;
+    })(Admin = /* istanbul ignore next */ Roles.Admin || (Roles.Admin = {}));
+    function isAdmin(role) {
+        return role === Role.Admin;
+    }
+    Roles.isAdmin = isAdmin;
+})(Roles = {});
+let fallback = {};
+var Legacy = fallback;
+(function(Legacy) {
+    Legacy.version = 1;
+})(/* istanbul ignore next */ Legacy || (Legacy = {}));
+var Scope = {
+    Owner: "owner"
+};
+var Scope;
+(function(Scope) {
+    Scope["Member"] = "member";
+})(/* istanbul ignore next */ Scope || (Scope = {}));
+function audit() {
+    var Level = {
+        Info: "info"
+    };
+    var Level;
+    (function(Level) {
+        Level["Warn"] = "warn";
+    })(/* istanbul ignore next */ Level || (Level = {}));
+    var Kind;
+    (function(Kind) {
+        Kind["Create"] = "create";
+    })(Kind = {});
+    return [
+        Level,
+        Kind
+    ];
+}
@@ -0,0 +1,40 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+const _graphql = require("@nestjs/graphql");
+var Role;
+(function(Role) {
+    Role["Admin"] = "admin";
+    Role["User"] = "user";
+})(Role || (Role = {}));
+(function(Role) {
+    Role["Guest"] = "guest";
+})(Role || (Role = {}));
+var Status;
+(function(Status) {
+    Status[Status["Active"] = 0] = "Active";
+    Status[Status["Disabled"] = 1] = "Disabled";
+})(Status || (Status = {}));
+(0, _graphql.registerEnumType)(Role, {
+    name: "Role"
+});
+(0, _graphql.registerEnumType)(Status, {
+    name: "Status"
+});
+var Roles;
+(function(Roles) {
+    let Admin;
+    (function(Admin) {
+        Admin.level = 1;
+    })(Admin = Roles.Admin || (Roles.Admin = {}));
+    function isAdmin(role) {
+        return role === Role.Admin;
+    }
+    Roles.isAdmin = isAdmin;
+})(Roles || (Roles = {}));
+let fallback = {};
+var Legacy = fallback;
+(function(Legacy) {
+    Legacy.version = 1;
+})(Legacy || (Legacy = {}));
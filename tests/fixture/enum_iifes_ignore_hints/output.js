@@ -0,0 +1,40 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+const _graphql = require("@nestjs/graphql");
+var Role;
+(function(Role) {
+    Role["Admin"] = "admin";
+    Role["User"] = "user";
+})(/* istanbul ignore next */ Role || (Role = {}));
+(function(Role) {
+    Role["Guest"] = "guest";
+})(/* istanbul ignore next */ Role || (Role = {}));
+var Status;
+(function(Status) {
+    Status[Status["Active"] = 0] = "Active";
+    Status[Status["Disabled"] = 1] = "Disabled";
+})(/* istanbul ignore next */ Status || (Status = {}));
+(0, _graphql.registerEnumType)(Role, {
+    name: "Role"
+});
+(0, _graphql.registerEnumType)(Status, {
+    name: "Status"
+});
+var Roles;
+(function(Roles) {
+    let Admin;
+    (function(Admin) {
+        Admin.level = 1;
+    })(Admin = /* istanbul ignore next */ Roles.Admin || (Roles.Admin = {}));
+    function isAdmin(role) {
+        return role === Role.Admin;
+    }
+    Roles.isAdmin = isAdmin;
+})(/* istanbul ignore next */ Roles || (Roles = {}));
+let fallback = {};
+var Legacy = fallback;
+(function(Legacy) {
+    Legacy.version = 1;
+})(/* istanbul ignore next */ Legacy || (Legacy = {}));
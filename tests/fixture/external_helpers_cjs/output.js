@@ -0,0 +1,25 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+const _ts_decorate = require("@swc/helpers/_/_ts_decorate");
+const _ts_metadata = require("@swc/helpers/_/_ts_metadata");
+const _ts_param = require("@swc/helpers/_/_ts_param");
+const _graphql = require("@nestjs/graphql");
+const _menuinput = require("./menu.input");
+class MenuResolver {
+    async updateMenu(input) {
+        return null;
+    }
+}
+(0, _ts_decorate._)([
+    (0, _graphql.Mutation)(Menu),
+    (0, _ts_param._)(0, (0, _graphql.Args)('input', {
+        type: _menuinput.MenuInput
+    })),
+    (0, _ts_metadata._)("design:type", Function),
+    (0, _ts_metadata._)("design:paramtypes", [
+        _menuinput.MenuInput
+    ]),
+    (0, _ts_metadata._)("design:returntype", Promise)
+], MenuResolver.prototype, "updateMenu", null);
@@ -0,0 +1,29 @@
+"use strict";
+const _ts_decorate = require("@swc/helpers/_/_ts_decorate")._;
+const _ts_metadata = require("@swc/helpers/_/_ts_metadata")._;
+const _ts_param = require("@swc/helpers/_/_ts_param")._;
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+const _graphql = require("@nestjs/graphql");
+class MenuResolver {
+    async getMenu(vendorID, id) {
+        return null;
+    }
+}
+_ts_decorate([
+    (0, _graphql.Query)(Menu),
+    _ts_param(0, (0, _graphql.Args)('vendorID', {
+        type: String,
+        nullable: false
+    })),
+    _ts_param(1, (0, _graphql.Args)('id', {
+        type: String
+    })),
+    _ts_metadata("design:type", Function),
+    _ts_metadata("design:paramtypes", [
+        String,
+        String
+    ]),
+    _ts_metadata("design:returntype", Promise)
+], MenuResolver.prototype, "getMenu", null);
@@ -0,0 +1,19 @@
+import { Field, ObjectType } from "@nestjs/graphql";
+function _ts_decorate(decorators, target, key, desc) {
+    var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc, d;
+    if (typeof Reflect === "object" && typeof Reflect.decorate === "function") r = Reflect.decorate(decorators, target, key, desc);
+    else for(var i = decorators.length - 1; i >= 0; i--)if (d = decorators[i]) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(target, key)) || r;
+    return c > 3 && r && Object.defineProperty(target, key, r), r;
+}
+function _ts_metadata1(k, v) {
+    if (typeof Reflect === "object" && typeof Reflect.metadata === "function") return Reflect.metadata(k, v);
+}
+export class MenuModel {
+}
+_ts_decorate([
+    Field(()=>String),
+    _ts_metadata1("design:type", String)
+], MenuModel.prototype, "name", void 0);
+MenuModel = _ts_decorate([
+    ObjectType()
+], MenuModel);
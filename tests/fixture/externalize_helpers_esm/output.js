@@ -0,0 +1,12 @@
+import { _ as _ts_decorate } from "@swc/helpers/_/_ts_decorate";
+import { _ as _ts_metadata1 } from "@swc/helpers/_/_ts_metadata";
+import { Field, ObjectType } from "@nestjs/graphql";
+export class MenuModel {
+}
+_ts_decorate([
+    Field(String),
+    _ts_metadata1("design:type", String)
+], MenuModel.prototype, "name", void 0);
+MenuModel = _ts_decorate([
+    ObjectType()
+], MenuModel);
@@ -0,0 +1,53 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+const _common = require("@nestjs/common");
+const _usersmodule = require("./users.module");
+const _usersservice = require("./users.service");
+function _ts_decorate(decorators, target, key, desc) {
+    var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc, d;
+    if (typeof Reflect === "object" && typeof Reflect.decorate === "function") r = Reflect.decorate(decorators, target, key, desc);
+    else for(var i = decorators.length - 1; i >= 0; i--)if (d = decorators[i]) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(key, r)) || r;
+    return c > 3 && r && Object.defineProperty(target, key, r), r;
+}
+function _ts_metadata(k, v) {
+    if (typeof Reflect === "object" && typeof Reflect.metadata === "function") return Reflect.metadata(k, v);
+}
+function _ts_param(paramIndex, decorator) {
+    return function(target, key) {
+        decorator(target, key, paramIndex);
+    };
+}
+let AuthService = class AuthService {
+    constructor(users, audit){
+        this.users = users;
+        this.audit = audit;
+    }
+};
+AuthService = _ts_decorate([
+    (0, _common.Injectable)(),
+    _ts_param(0, (0, _common.Inject)((0, _common.forwardRef)(Reflect.get.bind(null, _usersservice, "UsersService")))),
+    _ts_param(1, (0, _common.Inject)((0, _common.forwardRef)(/* istanbul ignore next */ ()=>AuditService))),
+    _ts_metadata("design:paramtypes", [
+        typeof _usersservice.UsersService === "undefined" ? Object : _usersservice.UsersService,
+        Object
+    ])
+], AuthService);
+let AuthModule = class AuthModule {
+};
+AuthModule = _ts_decorate([
+    (0, _common.Module)({
+        imports: [
+            (0, _common.forwardRef)(Reflect.get.bind(null, _usersmodule, "UsersModule")),
+            (0, _common.forwardRef)(/* istanbul ignore next */ ()=>AuditModule)
+        ],
+        providers: [
+            AuthService
+        ]
+    })
+], AuthModule);
+let AuditService = class AuditService {
+};
+let AuditModule = class AuditModule {
+};
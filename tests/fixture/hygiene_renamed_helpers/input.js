@@ -0,0 +1,26 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+const _graphql = require("@nestjs/graphql");
+const _ts_decorate = require("./legacy-decorate");
+function _ts_decorate1(decorators, target, key, desc) {
+    var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc, d;
+    if (typeof Reflect === "object" && typeof Reflect.decorate === "function") r = Reflect.decorate(decorators, target, key, desc);
+    else for(var i = decorators.length - 1; i >= 0; i--)if (d = decorators[i]) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(target, key)) || r;
+    return c > 3 && r && Object.defineProperty(target, key, r), r;
+}
+function _ts_metadata1(k, v) {
+    if (typeof Reflect === "object" && typeof Reflect.metadata === "function") return Reflect.metadata(k, v);
+}
+class MenuResolver {
+    menu() {
+        return null;
+    }
+}
+_ts_decorate1([
+    (0, _graphql.Query)(()=>Menu),
+    _ts_metadata1("design:type", Function),
+    _ts_metadata1("design:paramtypes", []),
+    _ts_metadata1("design:returntype", void 0)
+], MenuResolver.prototype, "menu", null);
@@ -0,0 +1,38 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+const _typeorm = require("typeorm");
+const _graphql = require("@nestjs/graphql");
+const _user = require("./user.entity");
+function _ts_decorate(decorators, target, key, desc) {
+    var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc, d;
+    if (typeof Reflect === "object" && typeof Reflect.decorate === "function") r = Reflect.decorate(decorators, target, key, desc);
+    else for(var i = decorators.length - 1; i >= 0; i--)if (d = decorators[i]) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(key, r)) || r;
+    return c > 3 && r && Object.defineProperty(target, key, r), r;
+}
+function _ts_metadata(k, v) {
+    if (typeof Reflect === "object" && typeof Reflect.metadata === "function") return Reflect.metadata(k, v);
+}
+class Photo {
+}
+_ts_decorate([
+    (0, _graphql.Field)(()=>String),
+    _ts_metadata("design:type", String)
+], Photo.prototype, "url", void 0);
+_ts_decorate([
+    (0, _typeorm.ManyToOne)(()=>_user.User, (user)=>user.photos),
+    (0, _graphql.Field)(()=>_user.User, {
+        nullable: true
+    }),
+    _ts_metadata("design:type", typeof _user.User === "undefined" ? Object : _user.User)
+], Photo.prototype, "owner", void 0);
+_ts_decorate([
+    (0, _graphql.Field)({
+        type: ()=>_user.User
+    }),
+    (0, _graphql.Field)(()=>[
+            _user.User
+        ]),
+    _ts_metadata("design:type", Array)
+], Photo.prototype, "likedBy", void 0);
@@ -10,6 +10,9 @@ _ts_decorate([
         fileIsRequired: false
     }))),
     _ts_metadata("design:type", Function),
-    _ts_metadata("design:paramtypes", [Object, Object]),
+    _ts_metadata("design:paramtypes", [
+        typeof ImageUpload === "undefined" ? Object : ImageUpload,
+        Object
+    ]),
     _ts_metadata("design:returntype", Promise)
 ], AppController.prototype, "uploadFile", null);
@@ -0,0 +1,49 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+const _mongoose = require("@nestjs/mongoose");
+const _swagger = require("@nestjs/swagger");
+const _graphql = require("@nestjs/graphql");
+const _address = require("./address.schema");
+const _tag = require("./tag.dto");
+function _ts_decorate(decorators, target, key, desc) {
+    var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc, d;
+    if (typeof Reflect === "object" && typeof Reflect.decorate === "function") r = Reflect.decorate(decorators, target, key, desc);
+    else for(var i = decorators.length - 1; i >= 0; i--)if (d = decorators[i]) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(key, r)) || r;
+    return c > 3 && r && Object.defineProperty(target, key, r), r;
+}
+function _ts_metadata(k, v) {
+    if (typeof Reflect === "object" && typeof Reflect.metadata === "function") return Reflect.metadata(k, v);
+}
+class Venue {
+}
+_ts_decorate([
+    (0, _mongoose.Prop)({
+        type: [
+            {
+                type: _address.Address
+            }
+        ]
+    }),
+    (0, _swagger.ApiProperty)({
+        items: {
+            type: _address.Address
+        }
+    }),
+    _ts_metadata("design:type", Array)
+], Venue.prototype, "addresses", void 0);
+_ts_decorate([
+    (0, _graphql.Field)({
+        complexity: 2,
+        options: {
+            type: _tag.Tag
+        }
+    }),
+    (0, _swagger.ApiProperty)([
+        {
+            type: _tag.Tag
+        }
+    ]),
+    _ts_metadata("design:type", Array)
+], Venue.prototype, "tags", void 0);
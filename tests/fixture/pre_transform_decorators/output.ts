@@ -0,0 +1,29 @@
+import { Args, Mutation, Query, Resolver, ResolveField } from "@nestjs/graphql";
+import { Inject } from "@nestjs/common";
+import { Menu } from "./menu.model";
+import { MenuService } from "./menu.service";
+@Resolver(Menu)
+export class MenuResolver {
+    constructor(
+    @Inject(MenuService)
+    private readonly menuService: MenuService){}
+    @Query([
+        Menu
+    ])
+    async menus(
+    @Args('vendorID', {
+        type: String
+    })
+    vendorID: string, 
+    @Args('filter')
+    filter: Express.Filter): Promise<Menu[]> {
+        return [];
+    }
+    @ResolveField(String)
+    status(menu: object): string {
+        return 'active';
+    }
+    helper(menu: Menu): Menu {
+        return menu;
+    }
+}
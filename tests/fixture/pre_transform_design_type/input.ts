@@ -0,0 +1,16 @@
+import { Prop, Schema } from "@nestjs/mongoose";
+import mongoose from "mongoose";
+
+@Schema()
+export class VenueModel {
+    @Prop({ type: () => mongoose.Types.ObjectId })
+    venueId: mongoose.Types.ObjectId;
+
+    @Prop()
+    name: string;
+
+    owner: mongoose.Types.ObjectId;
+
+    @Prop()
+    update(@Inject() input: UpdateVenueInput) {}
+}
@@ -0,0 +1,14 @@
+import { Prop, Schema } from "@nestjs/mongoose";
+import mongoose from "mongoose";
+@Schema()
+export class VenueModel {
+    @Prop({
+        type: mongoose.Types.ObjectId
+    })
+    venueId: object;
+    @Prop()
+    name: string;
+    owner: mongoose.Types.ObjectId;
+    @Prop()
+    update(@Inject() input: UpdateVenueInput) {}
+}
@@ -0,0 +1,17 @@
+import { Prop, Schema } from "@nestjs/mongoose";
+import { Field, ObjectType } from "@nestjs/graphql";
+import * as validator from "class-validator";
+import mongoose from "mongoose";
+@Schema()
+@ObjectType()
+export class VenueModel {
+    @Prop({
+        type: mongoose.Types.ObjectId
+    })
+    owner: mongoose.Types.ObjectId;
+    @Field(String)
+    @validator.IsOptional()
+    managerId: object;
+    @validator.IsOptional()
+    createdBy: object;
+}
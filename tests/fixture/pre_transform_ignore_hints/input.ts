@@ -0,0 +1,10 @@
+import { Args, Query, Resolver } from "@nestjs/graphql";
+import { Menu } from "./menu.model";
+
+@Resolver(() => Menu)
+export class MenuResolver {
+    @Query(() => [Menu])
+    async menus(@Args('filter', { type: () => { return String; } }) filter: Express.Filter): Promise<Menu[]> {
+        return [];
+    }
+}
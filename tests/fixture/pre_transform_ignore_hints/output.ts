@@ -0,0 +1,10 @@
+import { Args, Query, Resolver } from "@nestjs/graphql";
+import { Menu } from "./menu.model";
+
+@Resolver(/* istanbul ignore next */ () => Menu)
+export class MenuResolver {
+    @Query(/* istanbul ignore next */ () => [Menu])
+    async menus(@Args('filter', { type: /* istanbul ignore next */ () => { return String; } }) filter: Express.Filter): Promise<Menu[]> {
+        return [];
+    }
+}
@@ -0,0 +1,16 @@
+import { ConfigService } from "@nestjs/config";
+import { Query, Resolver } from "@nestjs/graphql";
+import * as geo from "./geo";
+
+class VenueFilter {}
+
+@Resolver()
+export class VenueResolver {
+    @Query()
+    location: geo.Point;
+
+    @Query()
+    find(filter: VenueFilter, cfg: ConfigService, file: Express.Multer.File, later: VenueSort) {}
+}
+
+class VenueSort {}
@@ -0,0 +1,14 @@
+import { ConfigService } from "@nestjs/config";
+import { Query, Resolver } from "@nestjs/graphql";
+import * as geo from "./geo";
+class VenueFilter {
+}
+@Resolver()
+export class VenueResolver {
+    @Query()
+    location: geo.Point;
+    @Query()
+    find(filter: VenueFilter, cfg: ConfigService, file: object, later: object) {}
+}
+class VenueSort {
+}
@@ -0,0 +1,19 @@
+import { Query, Resolver } from "@nestjs/graphql";
+import { VenueModel } from "./venue.model";
+
+@Resolver()
+export class VenueResolver {
+    @Query(() => VenueModel)
+    venue(): VenueModel {
+        return new VenueModel();
+    }
+
+    @Query(() => [VenueModel])
+    async venues(): Promise<VenueModel[]> {
+        return [];
+    }
+
+    helper(): VenueModel {
+        return new VenueModel();
+    }
+}
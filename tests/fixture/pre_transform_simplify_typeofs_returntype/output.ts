@@ -0,0 +1,18 @@
+import { Query, Resolver } from "@nestjs/graphql";
+import { VenueModel } from "./venue.model";
+@Resolver()
+export class VenueResolver {
+    @Query(VenueModel)
+    venue(): object {
+        return new VenueModel();
+    }
+    @Query([
+        VenueModel
+    ])
+    async venues(): object {
+        return [];
+    }
+    helper(): VenueModel {
+        return new VenueModel();
+    }
+}
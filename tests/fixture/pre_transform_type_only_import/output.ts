@@ -0,0 +1,14 @@
+import { Controller, Inject, Post } from "@nestjs/common";
+import { UploadService } from "./upload.service";
+import { VenueEntity } from "./venue.entity";
+import { type CreateVenueInput } from "./venue.input";
+@Controller()
+export class VenueController {
+    constructor(
+    @Inject(UploadService)
+    private readonly uploads: UploadService){}
+    @Post()
+    create(input: object, venue: VenueEntity, upload: object) {
+        return this.uploads.save(input, venue, upload);
+    }
+}
@@ -0,0 +1,33 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+function _export(target, all) {
+    for(var name in all)Object.defineProperty(target, name, {
+        enumerable: true,
+        get: all[name]
+    });
+}
+_export(exports, {
+    MenuModule: function() {
+        return _menumodule.MenuModule;
+    },
+    MenuService: function() {
+        return MenuService;
+    },
+    default: function() {
+        return _menuresolver.default;
+    }
+});
+const _menumodule = require("./menu.module");
+const _menuresolver = /*#__PURE__*/ _interop_require_default(require("./menu.resolver"));
+const _menuinput = require("./menu.input");
+Object.defineProperty(exports, "MenuInput", {
+    enumerable: true,
+    get: function() {
+        return _menuinput.MenuInput;
+    }
+});
+_export_star(require("./menu.model"), exports);
+class MenuService {
+}
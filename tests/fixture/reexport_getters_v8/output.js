@@ -0,0 +1,31 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+function _export(target, all) {
+    for(var name in all)Object.defineProperty(target, name, {
+        enumerable: true,
+        get: all[name]
+    });
+}
+_export(exports, {
+    MenuModule: /* c8 ignore start */ function() {
+        return _menumodule.MenuModule;
+    } /* c8 ignore stop */ ,
+    MenuService: /* c8 ignore start */ function() {
+        return MenuService;
+    } /* c8 ignore stop */ ,
+    default: /* c8 ignore start */ function() {
+        return _menuresolver.default;
+    } /* c8 ignore stop */ 
+});
+const _menumodule = require("./menu.module");
+const _menuresolver = /*#__PURE__*/ _interop_require_default(require("./menu.resolver"));
+const _menuinput = require("./menu.input");
+Object.defineProperty(exports, "MenuInput", {
+    enumerable: true,
+    get: Reflect.get.bind(null, _menuinput, "MenuInput")
+});
+_export_star(require("./menu.model"), exports);
+class MenuService {
+}
@@ -0,0 +1,40 @@
+"use strict";
+const _async_to_generator = require("@swc/helpers/_/_async_to_generator")._;
+const _define_property = require("@swc/helpers/_/_define_property")._;
+const _interop_require_default = require("@swc/helpers/_/_interop_require_default")._;
+const _ts_decorate = require("@swc/helpers/_/_ts_decorate")._;
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+function _export(target, all) {
+    for(var name in all)Object.defineProperty(target, name, {
+        enumerable: true,
+        get: all[name]
+    });
+}
+_export(exports, {
+    MenuService: function() {
+        return MenuService;
+    }
+});
+const _common = require("@nestjs/common");
+const _mongoose = /*#__PURE__*/ _interop_require_default(require("mongoose"));
+function _define_property_name(obj) {
+    return obj;
+}
+function _define_property1(key) {
+    return key;
+}
+class MenuService {
+    find() {
+        return _async_to_generator(function*() {
+            return _mongoose.default.model(this.name);
+        }).call(this);
+    }
+    constructor(){
+        _define_property(this, "name", "Menu");
+    }
+}
+MenuService = _ts_decorate([
+    (0, _common.Injectable)()
+], MenuService);
@@ -0,0 +1,44 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+const _common = require("@nestjs/common");
+const _config = require("@nestjs/config");
+const _mongoose = /*#__PURE__*/ _interop_require_default(require("mongoose"));
+const _menuinput = require("./menu.input");
+class AuditLog {
+}
+class MenuService {
+    constructor(config, audit, logger){
+        this.config = config;
+    }
+    update(input, owner, file, audit) {
+        return null;
+    }
+}
+_ts_decorate([
+    (0, _common.Optional)(),
+    _ts_metadata("design:type", _mongoose.default.Types.ObjectId)
+], MenuService.prototype, "ownerId", void 0);
+_ts_decorate([
+    (0, _common.Post)(),
+    _ts_metadata("design:type", Function),
+    _ts_metadata("design:paramtypes", [
+        _menuinput.MenuInput,
+        Object,
+        Object,
+        AuditLog
+    ]),
+    _ts_metadata("design:returntype", void 0)
+], MenuService.prototype, "update", null);
+MenuService = _ts_decorate([
+    (0, _common.Injectable)(),
+    _ts_metadata("design:type", Function),
+    _ts_metadata("design:paramtypes", [
+        _config.ConfigService,
+        AuditLog,
+        typeof Logger === "undefined" ? Object : Logger
+    ])
+], MenuService);
+class Owner {
+}
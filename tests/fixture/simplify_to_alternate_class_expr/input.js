@@ -0,0 +1,38 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+const _graphql = require("@nestjs/graphql");
+function _ts_decorate(decorators, target, key, desc) {
+    var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc, d;
+    if (typeof Reflect === "object" && typeof Reflect.decorate === "function") r = Reflect.decorate(decorators, target, key, desc);
+    else for(var i = decorators.length - 1; i >= 0; i--)if (d = decorators[i]) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(target, key)) || r;
+    return c > 3 && r && Object.defineProperty(target, key, r), r;
+}
+function _ts_metadata(k, v) {
+    if (typeof Reflect === "object" && typeof Reflect.metadata === "function") return Reflect.metadata(k, v);
+}
+let CreateVenueInput = class CreateVenueInput {
+};
+CreateVenueInput = _ts_decorate([
+    (0, _graphql.InputType)()
+], CreateVenueInput);
+class VenueResolver {
+    create(input, meta) {
+        return input;
+    }
+}
+_ts_decorate([
+    (0, _graphql.Mutation)(()=>Boolean),
+    _ts_metadata("design:type", Function),
+    _ts_metadata("design:paramtypes", [
+        typeof CreateVenueInput === "undefined" ? Object : CreateVenueInput,
+        typeof VenueMeta === "undefined" ? Object : VenueMeta
+    ]),
+    _ts_metadata("design:returntype", void 0)
+], VenueResolver.prototype, "create", null);
+let VenueMeta = class VenueMeta {
+};
+VenueMeta = _ts_decorate([
+    (0, _graphql.InputType)()
+], VenueMeta);
@@ -0,0 +1,26 @@
+"use strict";
+var _a, _b, _c, _d;
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.UploadController = void 0;
+const tslib_1 = require("tslib");
+const common_1 = require("@nestjs/common");
+const venue_service_1 = require("./venue.service");
+let UploadController = class UploadController {
+    constructor(venueService) {
+        this.venueService = venueService;
+    }
+    upload(file) {
+        return null;
+    }
+};
+exports.UploadController = UploadController;
+tslib_1.__decorate([
+    (0, common_1.Post)(),
+    tslib_1.__metadata("design:type", Function),
+    tslib_1.__metadata("design:paramtypes", [typeof (_b = typeof Express !== "undefined" && (_c = Express.Multer) !== void 0 && _c.File) === "function" ? _b : Object]),
+    tslib_1.__metadata("design:returntype", void 0)
+], UploadController.prototype, "upload", null);
+exports.UploadController = UploadController = tslib_1.__decorate([
+    (0, common_1.Controller)(),
+    tslib_1.__metadata("design:paramtypes", [typeof (_a = typeof venue_service_1.VenueService !== "undefined" && venue_service_1.VenueService) === "function" ? _a : Object, typeof (_d = typeof Unknown !== "undefined" && Unknown) === "function" ? _d : Object])
+], UploadController);
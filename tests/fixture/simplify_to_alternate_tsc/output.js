@@ -0,0 +1,33 @@
+"use strict";
+var _d;
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+exports.UploadController = void 0;
+const tslib_1 = require("tslib");
+const common_1 = require("@nestjs/common");
+const venue_service_1 = require("./venue.service");
+let UploadController = class UploadController {
+    constructor(venueService){
+        this.venueService = venueService;
+    }
+    upload(file) {
+        return null;
+    }
+};
+exports.UploadController = UploadController;
+tslib_1.__decorate([
+    (0, common_1.Post)(),
+    tslib_1.__metadata("design:type", Function),
+    tslib_1.__metadata("design:paramtypes", [
+        Object
+    ]),
+    tslib_1.__metadata("design:returntype", void 0)
+], UploadController.prototype, "upload", null);
+exports.UploadController = UploadController = tslib_1.__decorate([
+    (0, common_1.Controller)(),
+    tslib_1.__metadata("design:paramtypes", [
+        venue_service_1.VenueService,
+        typeof (_d = typeof Unknown !== "undefined" && Unknown) === "function" ? _d : Object
+    ])
+], UploadController);
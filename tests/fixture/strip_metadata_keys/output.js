@@ -0,0 +1,35 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+const _graphql = require("@nestjs/graphql");
+const _venueservice = require("./venue.service");
+const _venuemodel = require("./venue.model");
+function _ts_decorate(decorators, target, key, desc) {
+    var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc, d;
+    if (typeof Reflect === "object" && typeof Reflect.decorate === "function") r = Reflect.decorate(decorators, target, key, desc);
+    else for(var i = decorators.length - 1; i >= 0; i--)if (d = decorators[i]) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(target, key)) || r;
+    return c > 3 && r && Object.defineProperty(target, key, r), r;
+}
+function _ts_metadata(k, v) {
+    if (typeof Reflect === "object" && typeof Reflect.metadata === "function") return Reflect.metadata(k, v);
+}
+class VenueResolver {
+    constructor(venueService){
+        this.venueService = venueService;
+    }
+    venue(id) {
+        return this.venueService.find(id);
+    }
+}
+_ts_decorate([
+    (0, _graphql.Query)(_venuemodel.Venue),
+    _ts_metadata("design:type", Function)
+], VenueResolver.prototype, "venue", null);
+VenueResolver = _ts_decorate([
+    (0, _graphql.Resolver)(_venuemodel.Venue),
+    _ts_metadata("design:type", Function),
+    _ts_metadata("design:paramtypes", [
+        typeof _venueservice.VenueService === "undefined" ? Object : _venueservice.VenueService
+    ])
+], VenueResolver);
@@ -0,0 +1,35 @@
+import { _ as _apply_decs_2311 } from "@swc/helpers/_/_apply_decs_2311";
+import { Field, ObjectType } from "@nestjs/graphql";
+let _initClass, _init_tags, _init_extra_tags;
+let _dec = ObjectType(), _dec1 = Field([
+    String
+]);
+let _MenuModel;
+class MenuModel {
+    static{
+        ({ e: [_init_tags, _init_extra_tags], c: [_MenuModel, _initClass] } = _apply_decs_2311(this, [
+            _dec
+        ], [
+            [
+                _dec1,
+                1,
+                "tags"
+            ]
+        ]));
+    }
+    #___private_tags = (_init_extra_tags(this), _init_tags(this, []));
+    /* c8 ignore start */ get tags() {
+        return this.#___private_tags;
+    /* c8 ignore stop */ }
+    /* c8 ignore start */ set tags(_v) {
+        this.#___private_tags = _v;
+    /* c8 ignore stop */ }
+    #label = "";
+    get label() {
+        return this.#label;
+    }
+    static{
+        _initClass();
+    }
+}
+export { _MenuModel as MenuModel };
@@ -0,0 +1,54 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+const _graphql = require("@nestjs/graphql");
+const _item = require("./item.model");
+var _dec, _initClass, _dec1, _init_name, _dec2, _init_items, _dec3, _init_tags;
+let _MenuModel;
+const defaultTags = (0, _graphql.Directive)(()=>Tag);
+_dec = (0, _graphql.ObjectType)({
+    implements: ()=>[Node]
+}), _dec1 = (0, _graphql.Field)(()=>String), _dec2 = (0, _graphql.Field)(()=>[
+    _item.Item
+]), _dec3 = (0, _graphql.Field)({
+    type: ()=>[String],
+    nullable: true
+});
+class MenuModel {
+    static{
+        ({ e: [_init_name, _init_items, _init_tags], c: [_MenuModel, _initClass] } = _apply_decs_2203_r(this, [
+            [
+                _dec1,
+                0,
+                "name"
+            ],
+            [
+                _dec2,
+                1,
+                "items"
+            ],
+            [
+                _dec3,
+                0,
+                "tags"
+            ]
+        ], [
+            _dec
+        ]));
+    }
+    constructor(){
+        this.name = _init_name(this);
+        this.tags = _init_tags(this);
+    }
+    #___private_items = _init_items(this);
+    get items() {
+        return this.#___private_items;
+    }
+    set items(_v) {
+        this.#___private_items = _v;
+    }
+    static{
+        _initClass();
+    }
+}
@@ -0,0 +1,32 @@
+import { _ as _apply_decs_2311 } from "@swc/helpers/_/_apply_decs_2311";
+import { Args, Query, Resolver } from "@nestjs/graphql";
+import { Menu } from "./menu.model";
+let _initClass, _init_menus, _init_extra_menus;
+let _dec = Resolver(()=>Menu);
+let _MenuResolver;
+class MenuResolver {
+    static{
+        ({ e: [_init_menus, _init_extra_menus], c: [_MenuResolver, _initClass] } = _apply_decs_2311(this, [
+            _dec
+        ], [
+            [
+                [
+                    Query(()=>[
+                            Menu
+                        ]),
+                    Args('vendorID', {
+                        type: ()=>String
+                    })
+                ],
+                2,
+                "menus"
+            ]
+        ]));
+    }
+    menus(vendorID) {
+        return [];
+    }
+    static{
+        _initClass();
+    }
+}
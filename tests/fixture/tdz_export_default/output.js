@@ -0,0 +1,15 @@
+import { _ as _ts_decorate } from "@swc/helpers/_/_ts_decorate";
+import { _ as _ts_metadata } from "@swc/helpers/_/_ts_metadata";
+import { Field } from "@nestjs/graphql";
+export class Author {
+}
+_ts_decorate([
+    Field(()=>Book),
+    _ts_metadata("design:type", Object)
+], Author.prototype, "latestBook", void 0);
+export default class Book {
+}
+_ts_decorate([
+    Field(Author),
+    _ts_metadata("design:type", typeof Author === "undefined" ? Object : Author)
+], Book.prototype, "author", void 0);
@@ -0,0 +1,40 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+const _graphql = require("@nestjs/graphql");
+function _ts_decorate(decorators, target, key, desc) {
+    var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc, d;
+    if (typeof Reflect === "object" && typeof Reflect.decorate === "function") r = Reflect.decorate(decorators, target, key, desc);
+    else for(var i = decorators.length - 1; i >= 0; i--)if (d = decorators[i]) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(key, r)) || r;
+    return c > 3 && r && Object.defineProperty(target, key, r), r;
+}
+function _ts_metadata(k, v) {
+    if (typeof Reflect === "object" && typeof Reflect.metadata === "function") return Reflect.metadata(k, v);
+}
+let Category = class Category {
+};
+_ts_decorate([
+    (0, _graphql.Field)(()=>Category, {
+        nullable: true
+    }),
+    _ts_metadata("design:type", Object)
+], Category.prototype, "parent", void 0);
+_ts_decorate([
+    (0, _graphql.Field)(()=>[
+            Product
+        ]),
+    _ts_metadata("design:type", Array)
+], Category.prototype, "products", void 0);
+Category = _ts_decorate([
+    (0, _graphql.ObjectType)()
+], Category);
+let Product = class Product {
+};
+_ts_decorate([
+    (0, _graphql.Field)(()=>Category),
+    _ts_metadata("design:type", Object)
+], Product.prototype, "category", void 0);
+Product = _ts_decorate([
+    (0, _graphql.ObjectType)()
+], Product);
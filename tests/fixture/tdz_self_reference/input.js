@@ -0,0 +1,64 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+const _graphql = require("@nestjs/graphql");
+var _dec, _initClass, _dec1, _init_parent;
+let _Category;
+_dec = (0, _graphql.ObjectType)(), _dec1 = (0, _graphql.Field)(()=>Category, {
+    nullable: true
+});
+class Category {
+    static{
+        ({ e: [_init_parent], c: [_Category, _initClass] } = _apply_decs_2203_r(this, [
+            [
+                _dec1,
+                0,
+                "parent"
+            ]
+        ], [
+            _dec
+        ]));
+    }
+    constructor(){
+        this.parent = _init_parent(this);
+    }
+    static{
+        _initClass();
+    }
+}
+function Paginated(itemType) {
+    var _dec, _initClass, _dec1, _init_items, _dec2, _init_next;
+    let _PaginatedType;
+    _dec = (0, _graphql.ObjectType)(), _dec1 = (0, _graphql.Field)(()=>[
+        itemType
+    ]), _dec2 = (0, _graphql.Field)(()=>PaginatedType, {
+        nullable: true
+    });
+    class PaginatedType {
+        static{
+            ({ e: [_init_items, _init_next], c: [_PaginatedType, _initClass] } = _apply_decs_2203_r(this, [
+                [
+                    _dec1,
+                    0,
+                    "items"
+                ],
+                [
+                    _dec2,
+                    0,
+                    "next"
+                ]
+            ], [
+                _dec
+            ]));
+        }
+        constructor(){
+            this.items = _init_items(this);
+            this.next = _init_next(this);
+        }
+        static{
+            _initClass();
+        }
+    }
+    return _PaginatedType;
+}
@@ -0,0 +1,44 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+const _common = require("@nestjs/common");
+const _classtransformer = require("class-transformer");
+const _graphql = require("@nestjs/graphql");
+const _lazy = require("./lazy");
+const _address = require("./address.dto");
+const _users = require("./users.service");
+function _ts_decorate(decorators, target, key, desc) {
+    var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc, d;
+    if (typeof Reflect === "object" && typeof Reflect.decorate === "function") r = Reflect.decorate(decorators, target, key, desc);
+    else for(var i = decorators.length - 1; i >= 0; i--)if (d = decorators[i]) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(key, r)) || r;
+    return c > 3 && r && Object.defineProperty(target, key, r), r;
+}
+function _ts_metadata(k, v) {
+    if (typeof Reflect === "object" && typeof Reflect.metadata === "function") return Reflect.metadata(k, v);
+}
+function _ts_param(paramIndex, decorator) {
+    return function(target, key) {
+        decorator(target, key, paramIndex);
+    };
+}
+class CreateUserDto {
+}
+_ts_decorate([
+    (0, _classtransformer.Type)(()=>_address.AddressDto),
+    (0, _graphql.Field)(()=>_address.AddressDto),
+    (0, _lazy.Lazy)(()=>_address.AddressDto),
+    _ts_metadata("design:type", typeof _address.AddressDto === "undefined" ? Object : _address.AddressDto)
+], CreateUserDto.prototype, "address", void 0);
+let AuthService = class AuthService {
+    constructor(users){
+        this.users = users;
+    }
+};
+AuthService = _ts_decorate([
+    (0, _common.Injectable)(),
+    _ts_param(0, (0, _common.Inject)((0, _common.forwardRef)(()=>_users.UsersService))),
+    _ts_metadata("design:paramtypes", [
+        typeof _users.UsersService === "undefined" ? Object : _users.UsersService
+    ])
+], AuthService);
@@ -0,0 +1,28 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+const _classtransformer = require("class-transformer");
+const _typeorm = require("typeorm");
+const _lazy = require("./lazy");
+const _address = require("./address.dto");
+const _user = require("./user.entity");
+function _ts_decorate(decorators, target, key, desc) {
+    var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc, d;
+    if (typeof Reflect === "object" && typeof Reflect.decorate === "function") r = Reflect.decorate(decorators, target, key, desc);
+    else for(var i = decorators.length - 1; i >= 0; i--)if (d = decorators[i]) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(target, key)) || r;
+    return c > 3 && r && Object.defineProperty(target, key, r), r;
+}
+class Photo {
+}
+_ts_decorate([
+    (0, _classtransformer.Type)(()=>_address.AddressDto, {
+        discriminator: undefined
+    })
+], Photo.prototype, "address", void 0);
+_ts_decorate([
+    (0, _typeorm.ManyToOne)(()=>_user.User, ()=>_user.User.photos, _user.User)
+], Photo.prototype, "user", void 0);
+_ts_decorate([
+    (0, _lazy.Lazy)(()=>_address.AddressDto, ()=>_user.User, _user.User)
+], Photo.prototype, "lazy", void 0);
@@ -0,0 +1,33 @@
+"use strict";
+var __decorate = (this && this.__decorate) || function (decorators, target, key, desc) {
+    var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc, d;
+    if (typeof Reflect === "object" && typeof Reflect.decorate === "function") r = Reflect.decorate(decorators, target, key, desc);
+    else for (var i = decorators.length - 1; i >= 0; i--) if (d = decorators[i]) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(target, key)) || r;
+    return c > 3 && r && Object.defineProperty(target, key, r), r;
+};
+var __metadata = (this && this.__metadata) || function (k, v) {
+    if (typeof Reflect === "object" && typeof Reflect.metadata === "function") return Reflect.metadata(k, v);
+};
+var __param = (this && this.__param) || function (paramIndex, decorator) {
+    return function (target, key) { decorator(target, key, paramIndex); }
+};
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.MenuResolver = void 0;
+const graphql_1 = require("@nestjs/graphql");
+const menu_model_1 = require("./menu.model");
+let MenuResolver = class MenuResolver {
+    async getMenu(vendorID) {
+        return null;
+    }
+};
+exports.MenuResolver = MenuResolver;
+__decorate([
+    (0, graphql_1.Query)(() => menu_model_1.Menu),
+    __param(0, (0, graphql_1.Args)('vendorID', { type: () => String })),
+    __metadata("design:type", Function),
+    __metadata("design:paramtypes", [String]),
+    __metadata("design:returntype", Promise)
+], MenuResolver.prototype, "getMenu", null);
+exports.MenuResolver = MenuResolver = __decorate([
+    (0, graphql_1.Resolver)(() => menu_model_1.Menu)
+], MenuResolver);
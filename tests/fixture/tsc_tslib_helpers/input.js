@@ -0,0 +1,26 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.AppController = void 0;
+const tslib_1 = require("tslib");
+const common_1 = require("@nestjs/common");
+const app_service_1 = require("./app.service");
+let AppController = class AppController {
+    constructor(appService) {
+        this.appService = appService;
+    }
+    find(id) {
+        return this.appService.find(id);
+    }
+};
+exports.AppController = AppController;
+tslib_1.__decorate([
+    (0, common_1.Get)(':id'),
+    tslib_1.__param(0, (0, common_1.Param)('id', { type: () => String })),
+    tslib_1.__metadata("design:type", Function),
+    tslib_1.__metadata("design:paramtypes", [String]),
+    tslib_1.__metadata("design:returntype", void 0)
+], AppController.prototype, "find", null);
+exports.AppController = AppController = (0, tslib_1.__decorate)([
+    (0, common_1.Controller)(),
+    (0, tslib_1.__metadata)("design:paramtypes", [app_service_1.AppService])
+], AppController);
@@ -0,0 +1,34 @@
+"use strict";
+var _a;
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+exports.VenueController = void 0;
+const tslib_1 = require("tslib");
+const common_1 = require("@nestjs/common");
+const venue_service_1 = require("./venue.service");
+const create_venue_dto_1 = require("./create-venue.dto");
+let VenueController = class VenueController {
+    constructor(venueService){
+        this.venueService = venueService;
+    }
+    upload(file) {
+        return this.venueService.upload(file);
+    }
+};
+exports.VenueController = VenueController;
+tslib_1.__decorate([
+    (0, common_1.Post)('upload'),
+    tslib_1.__param(0, (0, common_1.UploadedFile)()),
+    tslib_1.__metadata("design:type", Function),
+    tslib_1.__metadata("design:paramtypes", [
+        Object
+    ]),
+    tslib_1.__metadata("design:returntype", void 0)
+], VenueController.prototype, "upload", null);
+exports.VenueController = VenueController = tslib_1.__decorate([
+    (0, common_1.Controller)('venues'),
+    tslib_1.__metadata("design:paramtypes", [
+        typeof (_a = typeof venue_service_1.VenueService !== "undefined" && venue_service_1.VenueService) === "function" ? _a : Object
+    ])
+], VenueController);
@@ -0,0 +1,19 @@
+"use strict";
+var _a, _b;
+Object.defineProperty(exports, "__esModule", { value: true });
+exports.VenueModel = void 0;
+const tslib_1 = require("tslib");
+const mongoose_1 = require("@nestjs/mongoose");
+const mongoose_2 = require("mongoose");
+const address_1 = require("./address");
+class VenueModel {
+}
+exports.VenueModel = VenueModel;
+tslib_1.__decorate([
+    (0, mongoose_1.Prop)(),
+    tslib_1.__metadata("design:type", typeof (_a = typeof mongoose_2.Types !== "undefined" && mongoose_2.Types.ObjectId) === "function" ? _a : Object)
+], VenueModel.prototype, "venueId", void 0);
+tslib_1.__decorate([
+    (0, mongoose_1.Prop)({ type: () => address_1.Address }),
+    tslib_1.__metadata("design:type", typeof (_b = typeof address_1.Address !== "undefined" && address_1.Address) === "function" ? _b : Object)
+], VenueModel.prototype, "address", void 0);
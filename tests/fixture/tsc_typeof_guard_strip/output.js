@@ -0,0 +1,20 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+exports.VenueModel = void 0;
+const tslib_1 = require("tslib");
+const mongoose_1 = require("@nestjs/mongoose");
+const mongoose_2 = require("mongoose");
+const address_1 = require("./address");
+class VenueModel {
+}
+exports.VenueModel = VenueModel;
+tslib_1.__decorate([
+    (0, mongoose_1.Prop)()
+], VenueModel.prototype, "venueId", void 0);
+tslib_1.__decorate([
+    (0, mongoose_1.Prop)({
+        type: address_1.Address
+    })
+], VenueModel.prototype, "address", void 0);
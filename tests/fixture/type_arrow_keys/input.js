@@ -0,0 +1,44 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+const _graphql = require("@nestjs/graphql");
+const _mongoose = require("@nestjs/mongoose");
+const _core = require("@mikro-orm/core");
+const _node = require("./node.interface");
+const _owner = require("./owner.schema");
+const _user = require("./user.entity");
+function _ts_decorate(decorators, target, key, desc) {
+    var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc, d;
+    if (typeof Reflect === "object" && typeof Reflect.decorate === "function") r = Reflect.decorate(decorators, target, key, desc);
+    else for(var i = decorators.length - 1; i >= 0; i--)if (d = decorators[i]) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(key, r)) || r;
+    return c > 3 && r && Object.defineProperty(target, key, r), r;
+}
+function _ts_metadata(k, v) {
+    if (typeof Reflect === "object" && typeof Reflect.metadata === "function") return Reflect.metadata(k, v);
+}
+let Venue = class Venue {
+};
+_ts_decorate([
+    (0, _mongoose.Prop)({
+        type: _mongoose.Schema.Types.ObjectId,
+        ref: ()=>_owner.Owner
+    }),
+    _ts_metadata("design:type", Object)
+], Venue.prototype, "owner", void 0);
+_ts_decorate([
+    (0, _graphql.Field)({
+        itemType: ()=>String
+    }),
+    (0, _core.ManyToOne)({
+        entity: ()=>_user.User
+    }),
+    _ts_metadata("design:type", Array)
+], Venue.prototype, "tags", void 0);
+Venue = _ts_decorate([
+    (0, _graphql.ObjectType)({
+        implements: ()=>[
+                _node.Node
+            ]
+    })
+], Venue);
@@ -0,0 +1,70 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+Object.defineProperty(exports, "VenueResolver", {
+    enumerable: true,
+    get: function() {
+        return VenueResolver;
+    }
+});
+const _graphql = require("@nestjs/graphql");
+const _venueservice = require("./venue.service");
+const _venuemodel = require("./venue.model");
+function _ts_decorate(decorators, target, key, desc) {
+    var c = arguments.length, r = c < 3 ? target : desc === null ? desc = Object.getOwnPropertyDescriptor(target, key) : desc, d;
+    if (typeof Reflect === "object" && typeof Reflect.decorate === "function") r = Reflect.decorate(decorators, target, key, desc);
+    else for(var i = decorators.length - 1; i >= 0; i--)if (d = decorators[i]) r = (c < 3 ? d(r) : c > 3 ? d(target, key, r) : d(key, r)) || r;
+    return c > 3 && r && Object.defineProperty(target, key, r), r;
+}
+function _ts_metadata(k, v) {
+    if (typeof Reflect === "object" && typeof Reflect.metadata === "function") return Reflect.metadata(k, v);
+}
+function _ts_param(paramIndex, decorator) {
+    return function(target, key) {
+        decorator(target, key, paramIndex);
+    };
+}
+let VenueResolver = class VenueResolver {
+    constructor(venueService){
+        this.venueService = venueService;
+    }
+    venues(limit) {
+        return this.venueService.findAll(limit);
+    }
+    search(city, limit) {
+        return this.venueService.search(city, limit);
+    }
+};
+_ts_decorate([
+    (0, _graphql.Query)(()=>[
+            _venuemodel.Venue
+        ], {
+        nullable: true
+    }),
+    _ts_param(0, (0, _graphql.Args)('limit', {
+        type: ()=>{
+            return _graphql.Int;
+        }
+    })),
+    _ts_metadata("design:type", Function),
+    _ts_metadata("design:paramtypes", [
+        typeof _graphql.Int === "undefined" ? Object : _graphql.Int
+    ]),
+    _ts_metadata("design:returntype", Promise)
+], VenueResolver.prototype, "venues", null);
+_ts_decorate([
+    (0, _graphql.Query)(()=>_venuemodel.Venue),
+    _ts_metadata("design:type", Function),
+    _ts_metadata("design:paramtypes", [
+        typeof _venuemodel.City === "undefined" ? Object : _venuemodel.City,
+        typeof _graphql.Int === "undefined" ? Object : _graphql.Int
+    ]),
+    _ts_metadata("design:returntype", Promise)
+], VenueResolver.prototype, "search", null);
+VenueResolver = _ts_decorate([
+    (0, _graphql.Resolver)(()=>_venuemodel.Venue),
+    _ts_metadata("design:paramtypes", [
+        typeof _venueservice.VenueService === "undefined" ? Object : _venueservice.VenueService
+    ])
+], VenueResolver);
@@ -0,0 +1,27 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+const _common = require("@nestjs/common");
+const _createmenudto = require("./dto/create-menu.dto");
+const _menuqueryargs = require("./dto/menu-query.args");
+class MenuController {
+    create(dto, query, id, file) {
+        return null;
+    }
+}
+_ts_decorate([
+    (0, _common.Post)(),
+    _ts_param(0, (0, _common.Body)()),
+    _ts_param(1, (0, _common.Query)()),
+    _ts_param(2, (0, _common.Param)('id')),
+    _ts_param(3, (0, _common.UploadedFile)()),
+    _ts_metadata("design:type", Function),
+    _ts_metadata("design:paramtypes", [
+        typeof _createmenudto.CreateMenuDto === "undefined" ? Object : _createmenudto.CreateMenuDto,
+        typeof _menuqueryargs.MenuQueryArgs === "undefined" ? Object : _menuqueryargs.MenuQueryArgs,
+        typeof MenuId === "undefined" ? Object : MenuId,
+        typeof Express === "undefined" || typeof Express.Multer === "undefined" || typeof Express.Multer.File === "undefined" ? Object : Express.Multer.File
+    ]),
+    _ts_metadata("design:returntype", void 0)
+], MenuController.prototype, "create", null);
@@ -1,13 +1,13 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use swc_core::ecma::{
-    parser::{EsSyntax, Syntax},
+    parser::{EsSyntax, Syntax, TsSyntax},
     transforms::testing::test_fixture,
     visit::visit_mut_pass,
 };
 
 use swc_jest_coverage_nestjs_plugin::{Config, PluginConfig, visitor::DecoratorCoverageVisitor};
 
-fn load_config(input: &PathBuf) -> Config {
+fn load_config(input: &Path) -> Config {
     let config_path = input.with_file_name("config.json");
     if config_path.exists() {
         let config_str = std::fs::read_to_string(&config_path).unwrap();
@@ -28,7 +28,35 @@ fn fixture_test(input: PathBuf) {
     let config = load_config(&input);
     test_fixture(
         Syntax::Es(EsSyntax::default()),
-        &|_| visit_mut_pass(DecoratorCoverageVisitor::new(config.clone())),
+        &|t| {
+            visit_mut_pass(
+                DecoratorCoverageVisitor::with_comments(config.clone(), Some(t.comments.clone()))
+                    .with_source_map(t.cm.clone()),
+            )
+        },
+        &input,
+        &output,
+        Default::default(),
+    );
+}
+
+/// Fixtures for the pre-transform path (`jsc.experimental.runPluginFirst`), where the
+/// plugin receives TypeScript with `Decorator` nodes still attached to classes.
+#[testing::fixture("tests/fixture/*/input.ts")]
+fn fixture_test_ts(input: PathBuf) {
+    let output = input.with_file_name("output.ts");
+    let config = load_config(&input);
+    test_fixture(
+        Syntax::Typescript(TsSyntax {
+            decorators: true,
+            ..Default::default()
+        }),
+        &|t| {
+            visit_mut_pass(
+                DecoratorCoverageVisitor::with_comments(config.clone(), Some(t.comments.clone()))
+                    .with_source_map(t.cm.clone()),
+            )
+        },
         &input,
         &output,
         Default::default(),